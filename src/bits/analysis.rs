@@ -0,0 +1,1016 @@
+// Bit Analysis Operations for Eidolon Math Library
+// This module contains functions that inspect and measure bit patterns and byte buffers
+// They are used for statistics such as histograms, entropy estimation and pattern metrics
+// Built on the bit operations from the bit_operations module wherever possible
+
+// Import the integer trait that provides the bit width and constants
+use crate::bits::ebm_int::EbmInt;
+
+// Import the lane replication used by the SWAR searches
+use crate::bits::bit_manipulation::ebm_broadcast_byte;
+
+// Import the bitmap reductions the similarity metric is built on, and the bit length for magnitudes
+use crate::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::{ebm_and_popcount, ebm_bit_length, ebm_or_popcount};
+
+// Import the basic operations the pattern metrics are built on
+use crate::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic::{ebm_add, ebm_mod, ebm_sub};
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::{ebm_and, ebmnot, ebmor, ebmxor};
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_and::{ebm_clear_bit, ebm_test_bit};
+use crate::bits::bit_operations::bitwise_shifting::bitwise_shifting::{ebm_left_shift, ebm_right_shift};
+
+/// Tallies how often each byte value occurs in a slice
+///
+/// # Arguments
+/// * `data` - The byte buffer to analyze
+///
+/// # Returns
+/// * `[u32; 256]` - The number of occurrences of every byte value, indexed by the byte
+///
+/// # Implementation Details
+/// This function builds the histogram in a single pass:
+/// 1. Starts from a zeroed 256-entry table on the stack
+/// 2. Uses every byte directly as the index of its bucket
+/// 3. Increments the bucket for each byte in the slice with `saturating_add`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - 1 KiB of stack for the table
+/// - Linear time in the length of the input
+/// - Cache-friendly sequential access of the input
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_byte_histogram;
+/// let histogram = ebm_byte_histogram(&[0, 0, 7]); // histogram[0] = 2, histogram[7] = 1
+/// let histogram = ebm_byte_histogram(&[]); // all buckets are 0
+/// ```
+///
+/// # Function Logic
+/// The histogram counts the number of distinct occurrences of each of the 256 possible byte
+/// values. It is the first step for entropy estimation and other frequency-based analysis.
+///
+/// # Safety Considerations
+/// - Uses only safe indexing (a byte is always a valid index into 256 buckets)
+/// - No undefined behavior possible
+/// - Buckets are `u32` and saturate at `u32::MAX` instead of overflowing, which takes 4 GiB of
+///   identical bytes
+pub fn ebm_byte_histogram(data: &[u8]) -> [u32; 256] {
+    // Start with all buckets empty
+    let mut histogram = [0u32; 256];
+
+    // Each byte value is its own bucket index; a full bucket stays at u32::MAX
+    for &byte in data {
+        histogram[byte as usize] = histogram[byte as usize].saturating_add(1);
+    }
+
+    histogram
+}
+
+/// Computes the Shannon entropy of a byte slice in bits per byte
+///
+/// # Arguments
+/// * `data` - The byte buffer to analyze
+///
+/// # Returns
+/// * `f64` - The entropy in bits per byte, between 0.0 and 8.0
+///
+/// # Implementation Details
+/// This function derives the entropy from the byte histogram:
+/// 1. Returns 0.0 immediately for an empty slice
+/// 2. Tallies every byte value with `ebm_byte_histogram`
+/// 3. Converts every non-empty bucket into a probability
+/// 4. Sums `-p * log2(p)` over all non-empty buckets
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Linear time in the length of the input plus a fixed 256-bucket pass
+/// - Cache-friendly sequential access of the input
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_shannon_entropy;
+/// let entropy = ebm_shannon_entropy(&[0x41; 64]); // 0.0 (a single repeated byte)
+/// let entropy = ebm_shannon_entropy(&[0, 1, 0, 1]); // 1.0 (two equally likely bytes)
+/// let entropy = ebm_shannon_entropy(&[]); // 0.0 (empty input)
+/// ```
+///
+/// # Function Logic
+/// Shannon entropy measures the average information content per byte. Text and structured
+/// data usually score well below 8.0, while compressed or encrypted payloads approach the
+/// maximum of 8.0 because every byte value is about equally likely.
+///
+/// # Safety Considerations
+/// - Uses only safe floating-point operations
+/// - Empty buckets are skipped, so `log2(0)` is never evaluated
+/// - The empty slice is handled explicitly instead of dividing by zero
+pub fn ebm_shannon_entropy(data: &[u8]) -> f64 {
+    // An empty buffer carries no information
+    if data.is_empty() {
+        return 0.0;
+    }
+
+    // Build the frequency table the probabilities are derived from
+    let histogram = ebm_byte_histogram(data);
+    let total = data.len() as f64;
+
+    // Sum -p * log2(p) over every byte value that actually occurs
+    let mut entropy = 0.0;
+    for &count in histogram.iter() {
+        if count == 0 {
+            continue;
+        }
+        let probability = count as f64 / total;
+        entropy -= probability * probability.log2();
+    }
+
+    entropy
+}
+
+/// Returns the length of the longest run of consecutive 1 bits
+///
+/// # Arguments
+/// * `a` - The operand to analyze
+///
+/// # Returns
+/// * `u32` - The length of the longest run of set bits (0 when `a` is 0)
+///
+/// # Implementation Details
+/// This function shrinks every run of ones by one bit per iteration:
+/// 1. Combines the value with itself shifted left by one using `ebm_and`
+/// 2. Every run of length `n` becomes a run of length `n - 1`
+/// 3. Counts the iterations until no set bits remain
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Iterates once per bit of the longest run, not once per bit of the type
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_longest_run_ones;
+/// let result = ebm_longest_run_ones(0b0111_0011u8); // 3
+/// let result = ebm_longest_run_ones(0u8); // 0
+/// let result = ebm_longest_run_ones(u16::MAX); // 16
+/// ```
+///
+/// # Function Logic
+/// A bit survives `a & (a << 1)` only if the bit below it is also set, so each iteration removes
+/// the lowest bit of every run. The longest run is the last one to disappear, which makes the
+/// iteration count equal to its length.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in operators
+/// - No undefined behavior possible with valid numeric types
+/// - Signed values are treated as their two's-complement bit pattern
+pub fn ebm_longest_run_ones<T: EbmInt>(a: T) -> u32 {
+    let mut remaining = a;
+    let mut length = 0u32;
+
+    // Remove the lowest bit of every run until nothing is left
+    while remaining != T::ZERO {
+        remaining = ebm_and(remaining, ebm_left_shift(remaining, 1u32));
+        length += 1;
+    }
+
+    length
+}
+
+/// Returns the length of the longest run of consecutive 0 bits
+///
+/// # Arguments
+/// * `a` - The operand to analyze
+///
+/// # Returns
+/// * `u32` - The length of the longest run of clear bits (0 when every bit is set)
+///
+/// # Implementation Details
+/// This function reuses the run-of-ones algorithm on the complement:
+/// 1. Inverts the value with `ebmnot` so zeros become ones
+/// 2. Measures the longest run of ones with `ebm_longest_run_ones`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Iterates once per bit of the longest run, not once per bit of the type
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_longest_run_zeros;
+/// let result = ebm_longest_run_zeros(0b0111_0011u8); // 2
+/// let result = ebm_longest_run_zeros(0u8); // 8
+/// let result = ebm_longest_run_zeros(u16::MAX); // 0
+/// ```
+///
+/// # Function Logic
+/// Complementing a value swaps the roles of its zeros and ones, so the longest run of zeros in
+/// `a` is the longest run of ones in `!a`. Leading zeros above the highest set bit count as a run.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in operators
+/// - No undefined behavior possible with valid numeric types
+/// - Signed values are treated as their two's-complement bit pattern
+pub fn ebm_longest_run_zeros<T: EbmInt>(a: T) -> u32 {
+    // Zeros of the value are the ones of its complement
+    ebm_longest_run_ones(ebmnot(a))
+}
+
+/// Counts the adjacent bit pairs whose bits differ
+///
+/// # Arguments
+/// * `a` - The operand to analyze
+///
+/// # Returns
+/// * `u32` - The number of 0/1 transitions between neighboring bits (at most `width - 1`)
+///
+/// # Implementation Details
+/// This function compares every bit with its upper neighbor in one step:
+/// 1. Shifts the value right by one so every bit lines up with its upper neighbor
+/// 2. XORs the shifted value with the original, setting a bit for every differing pair
+/// 3. Clears the top bit, which has no upper neighbor inside the type
+/// 4. Counts the remaining set bits
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Branchless, one shift, one XOR, one AND and one population count
+/// - Hardware-accelerated when possible (POPCNT instruction)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_count_transitions;
+/// let result = ebm_count_transitions(0b1010_1010u8); // 7
+/// let result = ebm_count_transitions(0x00u8); // 0
+/// let result = ebm_count_transitions(0x0Fu8); // 1
+/// ```
+///
+/// # Function Logic
+/// The transition count measures how "busy" a bit pattern is: constant patterns have none and
+/// alternating patterns have the maximum of `width - 1`. The top bit is masked out because the
+/// bit shifted in above it (zero or a sign copy) is not part of the value.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in operators
+/// - No undefined behavior possible with valid numeric types
+/// - Gives the same result for signed and unsigned types with the same bit pattern
+pub fn ebm_count_transitions<T: EbmInt>(a: T) -> u32 {
+    // Set a bit for every position that differs from the bit above it
+    let differences = ebmxor(a, ebm_right_shift(a, 1u32));
+
+    // The top bit was compared against a shifted-in bit, not a real neighbor
+    ebm_clear_bit(differences, T::BITS - 1).count_ones()
+}
+
+/// Computes the Fletcher-16 checksum of a byte slice
+///
+/// # Arguments
+/// * `data` - The message bytes to checksum
+///
+/// # Returns
+/// * `u16` - The checksum with the second sum in the high byte and the first sum in the low byte
+///
+/// # Implementation Details
+/// This function keeps two running sums modulo 255:
+/// 1. Adds every byte to the first sum with `ebm_add` and reduces it with `ebm_mod`
+/// 2. Adds the first sum to the second sum after every byte and reduces it the same way
+/// 3. Packs the second sum into the high byte and the first sum into the low byte
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Linear time in the length of the buffer
+/// - Both sums stay below 255, so the `u16` additions can never overflow
+/// - Cache-friendly sequential access of the buffer
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_fletcher16;
+/// let result = ebm_fletcher16(b"abcde"); // 0xC8F0
+/// let result = ebm_fletcher16(b"abcdef"); // 0x2057
+/// let result = ebm_fletcher16(&[]); // 0x0000
+/// ```
+///
+/// # Function Logic
+/// The first sum detects changed byte values and the second sum, which weights every byte by
+/// its distance from the end, detects reordered bytes. This makes Fletcher-16 noticeably
+/// stronger than a plain sum or XOR checksum at almost the same cost.
+///
+/// # Safety Considerations
+/// - Uses only safe arithmetic that cannot overflow
+/// - No undefined behavior possible
+/// - Not a cryptographic integrity check
+pub fn ebm_fletcher16(data: &[u8]) -> u16 {
+    // Both sums are kept modulo 255
+    let mut sum1: u16 = 0;
+    let mut sum2: u16 = 0;
+
+    // Accumulate the byte sum and the sum of the running sums
+    for &byte in data {
+        sum1 = ebm_mod(ebm_add(sum1, byte as u16), 255);
+        sum2 = ebm_mod(ebm_add(sum2, sum1), 255);
+    }
+
+    // Pack the second sum above the first
+    (sum2 << 8) | sum1
+}
+
+/// Iterates over the maximal runs of equal bits from the least significant bit upward
+///
+/// # Arguments
+/// * `a` - The value whose runs are listed
+///
+/// # Returns
+/// * `impl Iterator<Item = (u32, u32, bool)>` - One `(start, length, bit)` tuple per run
+///
+/// # Implementation Details
+/// This function measures one run per iteration step:
+/// 1. Reads the bit at the current position with `ebm_test_bit`
+/// 2. Shifts the value down so the run starts at bit 0
+/// 3. Measures the run with `trailing_zeros`, inverting the value first for a run of ones
+/// 4. Caps the length at the remaining width, since shifted-in bits are not part of the value
+/// 5. Advances the position past the run and stops at the bit width
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One shift and one hardware trailing-zero count per run, not per bit
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_runs;
+/// let runs: Vec<_> = ebm_runs(0b0011_1000u8).collect(); // [(0, 3, false), (3, 3, true), (6, 2, false)]
+/// let runs: Vec<_> = ebm_runs(0u16).collect(); // [(0, 16, false)]
+/// ```
+///
+/// # Function Logic
+/// Status words often consist of a few long runs, so run-length encoding them is compact. The
+/// runs always cover every bit exactly once: their lengths add up to the bit width, and
+/// consecutive runs alternate between zeros and ones.
+///
+/// # Safety Considerations
+/// - Never shifts by the full width or more
+/// - No undefined behavior possible with valid numeric types
+/// - Signed values are treated as their two's-complement bit pattern
+pub fn ebm_runs<T: EbmInt>(a: T) -> impl Iterator<Item = (u32, u32, bool)> {
+    let mut position = 0u32;
+
+    std::iter::from_fn(move || {
+        // Every bit has been covered by a run
+        if position >= T::BITS {
+            return None;
+        }
+
+        // Measure the run of equal bits starting at the current position
+        let bit = ebm_test_bit(a, position);
+        let shifted = ebm_right_shift(a, position);
+        let run = if bit { ebmnot(shifted) } else { shifted };
+        let length = run.trailing_zeros().min(T::BITS - position);
+
+        let start = position;
+        position += length;
+        Some((start, length, bit))
+    })
+}
+
+// Every byte of a 64-bit word set to 0x01, 0x7F and 0x80
+const LOW_BITS_U64: u64 = 0x0101_0101_0101_0101;
+const LOW_SEVEN_BITS_U64: u64 = 0x7F7F_7F7F_7F7F_7F7F;
+const HIGH_BITS_U64: u64 = 0x8080_8080_8080_8080;
+
+// Flags the high bit of the lowest zero byte, and possibly of bytes above it
+fn zero_byte_flags(word: u64) -> u64 {
+    ebm_and(ebm_and(word.wrapping_sub(LOW_BITS_U64), ebmnot(word)), HIGH_BITS_U64)
+}
+
+// Flags the high bit of exactly the zero bytes, using carry-free per-lane additions
+fn exact_zero_byte_flags(word: u64) -> u64 {
+    // Adding 0x7F to the low seven bits carries into bit 7 unless they are all zero
+    let nonzero = ebmor(ebm_add(ebm_and(word, LOW_SEVEN_BITS_U64), LOW_SEVEN_BITS_U64), word);
+    ebm_and(ebmnot(nonzero), HIGH_BITS_U64)
+}
+
+/// Returns whether any of the eight bytes of a word is zero
+///
+/// # Arguments
+/// * `word` - Eight bytes packed into a `u64`
+///
+/// # Returns
+/// * `bool` - `true` if at least one byte of `word` is zero
+///
+/// # Implementation Details
+/// This function uses the classic SWAR zero-byte test:
+/// 1. Subtracts `0x01` from every byte at once with `word - 0x0101010101010101`
+/// 2. Keeps only bytes whose high bit was clear before with `& !word`
+/// 3. Masks the high bit of every byte with `& 0x8080808080808080`
+/// 4. Any remaining bit means a byte borrowed, which only a zero byte can start
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One subtraction, one NOT and two ANDs for eight bytes
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_has_zero_byte;
+/// let result = ebm_has_zero_byte(u64::from_le_bytes(*b"abc\0defg")); // true
+/// let result = ebm_has_zero_byte(u64::from_le_bytes(*b"abcdefgh")); // false
+/// ```
+///
+/// # Function Logic
+/// A byte only borrows from the subtraction when it is zero, or when the byte below it borrowed.
+/// The `!word` term removes bytes that had their high bit set, so the result is non-zero exactly
+/// when the word contains a zero byte. This lets NUL terminators be found a word at a time.
+///
+/// # Safety Considerations
+/// - The subtraction deliberately wraps
+/// - No undefined behavior possible
+pub fn ebm_has_zero_byte(word: u64) -> bool {
+    zero_byte_flags(word) != 0
+}
+
+/// Returns the index of the lowest zero byte of a word
+///
+/// # Arguments
+/// * `word` - Eight bytes packed into a `u64`, byte 0 being the least significant
+///
+/// # Returns
+/// * `Option<u32>` - The index of the lowest zero byte, or `None` if no byte is zero
+///
+/// # Implementation Details
+/// This function locates the lowest flag of the SWAR zero-byte test:
+/// 1. Computes the flags of `ebm_has_zero_byte`
+/// 2. Returns `None` when no flag is set
+/// 3. Otherwise divides the trailing zero count of the flags by eight
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - The zero-byte test plus one hardware trailing-zero count
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_zero_byte_index;
+/// let result = ebm_zero_byte_index(u64::from_le_bytes(*b"abc\0defg")); // Some(3)
+/// let result = ebm_zero_byte_index(u64::from_le_bytes(*b"abcdefgh")); // None
+/// ```
+///
+/// # Function Logic
+/// The SWAR test may also flag a `0x01` byte directly above a zero byte because of the borrow,
+/// but such false flags only ever appear above a real zero byte. The lowest flag is therefore
+/// always exact. Loading words in little-endian order makes the index the offset of the NUL
+/// terminator within the word.
+///
+/// # Safety Considerations
+/// - The subtraction deliberately wraps
+/// - No undefined behavior possible
+pub fn ebm_zero_byte_index(word: u64) -> Option<u32> {
+    let flags = zero_byte_flags(word);
+
+    // No flag means no zero byte
+    if flags == 0 {
+        return None;
+    }
+
+    // The lowest flag is exact, false flags only occur above it
+    Some(flags.trailing_zeros() / 8)
+}
+
+/// Returns whether any of the eight bytes of a word equals a given byte
+///
+/// # Arguments
+/// * `word` - Eight bytes packed into a `u64`
+/// * `needle` - The byte value to search for
+///
+/// # Returns
+/// * `bool` - `true` if at least one byte of `word` equals `needle`
+///
+/// # Implementation Details
+/// This function reduces the search to the zero-byte test:
+/// 1. Copies `needle` into all eight bytes with `ebm_broadcast_byte`
+/// 2. XORs the word with that pattern, which turns every matching byte into zero
+/// 3. Applies the SWAR zero-byte test of `ebm_has_zero_byte`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One broadcast multiplication and one XOR on top of the zero-byte test
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_has_byte;
+/// let result = ebm_has_byte(u64::from_le_bytes(*b"abcdefgh"), b'e'); // true
+/// let result = ebm_has_byte(u64::from_le_bytes(*b"abcdefgh"), b'z'); // false
+/// ```
+///
+/// # Function Logic
+/// `x ^ y` is zero exactly when `x == y`, so a lane-wise XOR with the needle maps matches to
+/// zero bytes. Scanning a buffer this way checks eight bytes per step like `memchr`.
+///
+/// # Safety Considerations
+/// - The subtraction in the zero-byte test deliberately wraps
+/// - No undefined behavior possible
+pub fn ebm_has_byte(word: u64, needle: u8) -> bool {
+    // Matching bytes become zero bytes
+    ebm_has_zero_byte(ebmxor(word, ebm_broadcast_byte(needle)))
+}
+
+/// Compares the eight byte lanes of two words for equality
+///
+/// # Arguments
+/// * `a` - The first eight bytes packed into a `u64`
+/// * `b` - The second eight bytes packed into a `u64`
+///
+/// # Returns
+/// * `u64` - `0xFF` in every byte lane where `a` and `b` are equal, `0x00` elsewhere
+///
+/// # Implementation Details
+/// This function runs a zero-byte test on the lane-wise difference:
+/// 1. XORs the words, which turns every equal lane into a zero byte
+/// 2. Adds `0x7F` to the low seven bits of every lane, which carries into bit 7 of every
+///    lane that is not all zero, and ORs in the original bits
+/// 3. Inverts the result and keeps bit 7 of every lane, flagging exactly the zero lanes
+/// 4. Widens every flag to a full `0xFF` lane by moving it to bit 0 and multiplying by `0xFF`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - About ten operations for eight lanes, no loops or branches
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_cmp_bytes_eq;
+/// let result = ebm_cmp_bytes_eq(0x1122_3344_5566_7788, 0x1122_0044_5566_7700); // 0xFFFF_00FF_FFFF_FF00
+/// let result = ebm_cmp_bytes_eq(0, 0); // u64::MAX
+/// ```
+///
+/// # Function Logic
+/// The subtraction-based test of `ebm_has_zero_byte` may also flag a `0x01` lane directly above
+/// a zero lane, which is harmless for a yes/no answer but wrong for per-lane results. Masking
+/// off bit 7 before adding keeps every lane's carry inside the lane, so this variant is exact
+/// for every lane. The resulting masks can select or filter lanes with plain AND operations.
+///
+/// # Safety Considerations
+/// - None of the additions can carry across lanes
+/// - No undefined behavior possible
+pub fn ebm_cmp_bytes_eq(a: u64, b: u64) -> u64 {
+    // Equal lanes become zero lanes, which are flagged in bit 7
+    let flags = exact_zero_byte_flags(ebmxor(a, b));
+
+    // Widen every flag bit to a full byte
+    ebm_right_shift(flags, 7u32) * 0xFF
+}
+
+/// Counts the occurrences of a byte in a slice, eight bytes at a time
+///
+/// # Arguments
+/// * `data` - The byte buffer to search
+/// * `needle` - The byte value to count
+///
+/// # Returns
+/// * `usize` - The number of bytes in `data` equal to `needle`
+///
+/// # Implementation Details
+/// This function combines the broadcast and zero-byte SWAR tricks:
+/// 1. Broadcasts the needle into every lane of a `u64`
+/// 2. Loads each full eight-byte chunk and XORs it with the broadcast, turning matches into zero bytes
+/// 3. Flags exactly the zero bytes and adds the popcount of the flags
+/// 4. Counts the remaining tail bytes one at a time
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - About ten word operations per eight bytes, independent of the number of matches
+/// - At most seven scalar comparisons for the tail
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_count_bytes_eq;
+/// let result = ebm_count_bytes_eq(b"a,b,,c,d,e", b','); // 5
+/// let result = ebm_count_bytes_eq(b"", b','); // 0
+/// ```
+///
+/// # Function Logic
+/// The subtraction-based flags of `ebm_has_zero_byte` may also mark a lane next to a match,
+/// which would overcount, so the carry-free exact flags of `ebm_cmp_bytes_eq` are used instead.
+/// Each flag is a single bit, so one popcount counts all matches in a chunk.
+///
+/// # Safety Considerations
+/// - Chunks are read with safe slice iteration, with no alignment requirements
+/// - None of the additions can carry across lanes
+/// - No undefined behavior possible
+pub fn ebm_count_bytes_eq(data: &[u8], needle: u8) -> usize {
+    let pattern = ebm_broadcast_byte(needle);
+    let mut chunks = data.chunks_exact(8);
+
+    // Count matches in every full word
+    let mut count = 0usize;
+    for chunk in &mut chunks {
+        let word = u64::from_le_bytes(chunk.try_into().expect("chunk of eight bytes"));
+        count += exact_zero_byte_flags(ebmxor(word, pattern)).count_ones() as usize;
+    }
+
+    // Count the tail bytes directly
+    count + chunks.remainder().iter().filter(|&&byte| byte == needle).count()
+}
+
+/// Adds eight packed `u8` lanes with saturation at 255
+///
+/// # Arguments
+/// * `a` - Eight `u8` lanes packed into a `u64`
+/// * `b` - Eight `u8` lanes packed into a `u64`
+///
+/// # Returns
+/// * `u64` - The lane-wise sums, with every overflowing lane clamped to `0xFF`
+///
+/// # Implementation Details
+/// This function performs a SWAR half-add followed by a saturation mask:
+/// 1. Adds the low seven bits of every lane, which can never carry into the next lane
+/// 2. Adds the top bits of every lane with an XOR, completing the wrapped lane sums
+/// 3. Computes the carry out of bit 7 of every lane as `(a & b) | ((a | b) & !sum)`
+/// 4. Widens every carry bit into a `0xFF` mask and ORs it into the sums
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - About a dozen operations for eight lanes, no loops or branches
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_swar_add_saturating_u8x8;
+/// let result = ebm_swar_add_saturating_u8x8(0x0102_03F0, 0x0101_0120); // 0x0203_04FF
+/// let result = ebm_swar_add_saturating_u8x8(u64::MAX, 1); // u64::MAX
+/// ```
+///
+/// # Function Logic
+/// A plain 64-bit addition would let the carry of one pixel channel spill into its neighbor.
+/// Splitting off the top bit of every lane keeps the additions inside their lanes, and the
+/// carry formula recovers exactly the lanes whose true sum exceeded 255. Those lanes are then
+/// forced to 255, which is how additive blending of 8-bit channels behaves.
+///
+/// # Safety Considerations
+/// - None of the additions can carry across lanes
+/// - No undefined behavior possible
+pub fn ebm_swar_add_saturating_u8x8(a: u64, b: u64) -> u64 {
+    // Add the low seven bits of every lane, then fold in the top bits without carries
+    let low = ebm_add(ebm_and(a, LOW_SEVEN_BITS_U64), ebm_and(b, LOW_SEVEN_BITS_U64));
+    let sum = ebmxor(low, ebm_and(ebmxor(a, b), HIGH_BITS_U64));
+
+    // A lane overflowed if both top bits were set, or one was set and the sum's top bit is clear
+    let carry = ebm_and(ebmor(ebm_and(a, b), ebm_and(ebmor(a, b), ebmnot(sum))), HIGH_BITS_U64);
+
+    // Clamp every overflowed lane to 0xFF
+    ebmor(sum, ebm_right_shift(carry, 7u32) * 0xFF)
+}
+
+// Sets every lane of the result to 0xFF where the lane of a is at least the lane of b
+fn lane_ge_mask(a: u64, b: u64) -> u64 {
+    // With bit 7 forced on in a and off in b no lane can borrow, and bit 7 survives
+    // exactly where the low seven bits of a are at least those of b
+    let low_ge = ebm_sub(ebmor(a, HIGH_BITS_U64), ebm_and(b, LOW_SEVEN_BITS_U64));
+
+    // The top bits decide unless they are equal, in which case the low bits do
+    let ge = ebm_and(ebmor(ebm_and(a, ebmnot(b)), ebm_and(ebmnot(ebmxor(a, b)), low_ge)), HIGH_BITS_U64);
+    ebm_right_shift(ge, 7u32) * 0xFF
+}
+
+/// Takes the lane-wise minimum of eight packed `u8` lanes
+///
+/// # Arguments
+/// * `a` - Eight `u8` lanes packed into a `u64`
+/// * `b` - Eight `u8` lanes packed into a `u64`
+///
+/// # Returns
+/// * `u64` - The smaller of the two bytes in every lane
+///
+/// # Implementation Details
+/// This function compares all lanes at once and then selects:
+/// 1. Subtracts the low seven bits of `b` from `a` with bit 7 forced on, so no lane borrows
+/// 2. Combines that result with the top bits to get an unsigned `a >= b` flag in every lane
+/// 3. Widens every flag to a full `0xFF` lane mask
+/// 4. Takes `b` in the flagged lanes and `a` in the others
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - About fifteen operations for eight lanes, no loops or branches
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_swar_min_u8x8;
+/// let result = ebm_swar_min_u8x8(0x10FF_8001, 0x2000_7F02); // 0x1000_7F01
+/// let result = ebm_swar_min_u8x8(u64::MAX, 0); // 0
+/// ```
+///
+/// # Function Logic
+/// Treating the top bit of every lane separately keeps the subtraction inside the lanes, the
+/// same split used by `ebm_swar_add_saturating_u8x8`. The resulting mask works as a
+/// branch-free select, which makes eight-way clamping of pixel channels cheap.
+///
+/// # Safety Considerations
+/// - None of the subtractions can borrow across lanes
+/// - No undefined behavior possible
+pub fn ebm_swar_min_u8x8(a: u64, b: u64) -> u64 {
+    let a_ge_b = lane_ge_mask(a, b);
+    ebmor(ebm_and(b, a_ge_b), ebm_and(a, ebmnot(a_ge_b)))
+}
+
+/// Takes the lane-wise maximum of eight packed `u8` lanes
+///
+/// # Arguments
+/// * `a` - Eight `u8` lanes packed into a `u64`
+/// * `b` - Eight `u8` lanes packed into a `u64`
+///
+/// # Returns
+/// * `u64` - The larger of the two bytes in every lane
+///
+/// # Implementation Details
+/// This function shares the lane comparison of `ebm_swar_min_u8x8`:
+/// 1. Computes the `a >= b` lane mask without cross-lane borrows
+/// 2. Takes `a` in the flagged lanes and `b` in the others
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - About fifteen operations for eight lanes, no loops or branches
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_swar_max_u8x8;
+/// let result = ebm_swar_max_u8x8(0x10FF_8001, 0x2000_7F02); // 0x20FF_8002
+/// let result = ebm_swar_max_u8x8(u64::MAX, 0); // u64::MAX
+/// ```
+///
+/// # Function Logic
+/// Combined with `ebm_swar_min_u8x8` this clamps eight bytes to a range in a handful of
+/// operations, or sorts pairs of lanes as a step of a sorting network.
+///
+/// # Safety Considerations
+/// - None of the subtractions can borrow across lanes
+/// - No undefined behavior possible
+pub fn ebm_swar_max_u8x8(a: u64, b: u64) -> u64 {
+    let a_ge_b = lane_ge_mask(a, b);
+    ebmor(ebm_and(a, a_ge_b), ebm_and(b, ebmnot(a_ge_b)))
+}
+
+/// Returns the index of the lowest bit where two values differ
+///
+/// # Arguments
+/// * `a` - The first value
+/// * `b` - The second value
+///
+/// # Returns
+/// * `Option<u32>` - The position of the lowest differing bit, or `None` if the values are equal
+///
+/// # Implementation Details
+/// This function locates the difference with one XOR:
+/// 1. Computes `a ^ b` with `ebmxor`, which has a set bit exactly where the values differ
+/// 2. Returns `None` when the XOR is zero
+/// 3. Otherwise returns its hardware trailing-zero count
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One XOR and one trailing-zero count (a single TZCNT instruction on some targets)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_first_diff_bit;
+/// let result = ebm_first_diff_bit(0b0110u8, 0b0100u8); // Some(1)
+/// let result = ebm_first_diff_bit(0x1234u16, 0x1234u16); // None
+/// ```
+///
+/// # Function Logic
+/// A trie keyed on hash bits from the least significant end branches at the first bit where
+/// two keys diverge. Everything below the returned index is shared by both values.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in methods
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_first_diff_bit<T: EbmInt>(a: T, b: T) -> Option<u32> {
+    let differences = ebmxor(a, b);
+
+    // Equal values have no differing bit
+    if differences == T::ZERO {
+        return None;
+    }
+    Some(differences.trailing_zeros())
+}
+
+/// Returns the number of leading bits two values share
+///
+/// # Arguments
+/// * `a` - The first value
+/// * `b` - The second value
+///
+/// # Returns
+/// * `u32` - The length of the common prefix counted from the most significant bit, equal to the
+///   bit width when the values are equal
+///
+/// # Implementation Details
+/// This function measures the prefix with one XOR:
+/// 1. Computes `a ^ b` with `ebmxor`, which is zero wherever the values agree
+/// 2. Counts the leading zeros of the XOR, which stops at the highest differing bit
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One XOR and one leading-zero count (a single LZCNT instruction on some targets)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_common_prefix_len;
+/// let result = ebm_common_prefix_len(0b1010_0000u8, 0b1011_0000u8); // 3
+/// let result = ebm_common_prefix_len(0x1234u16, 0x1235u16); // 15
+/// let result = ebm_common_prefix_len(7u32, 7u32); // 32
+/// ```
+///
+/// # Function Logic
+/// This is the counterpart of `ebm_first_diff_bit` for keys compared from the most significant
+/// end. A binary radix tree stores at each node the prefix shared by all keys below it, and
+/// this length decides where a new key splits off.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in methods
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_common_prefix_len<T: EbmInt>(a: T, b: T) -> u32 {
+    // The prefix ends at the highest bit where the values differ
+    ebmxor(a, b).leading_zeros()
+}
+
+/// Computes the Tanimoto (Jaccard) similarity of two bitmaps
+///
+/// # Arguments
+/// * `a` - The first bitmap, packed into 64-bit words
+/// * `b` - The second bitmap, packed the same way
+///
+/// # Returns
+/// * `f64` - The number of shared set bits divided by the number of bits set in either bitmap,
+///   between 0.0 and 1.0
+///
+/// # Implementation Details
+/// This function divides the two bitmap reductions:
+/// 1. Counts the union with `ebm_or_popcount`, which also checks that the lengths match
+/// 2. Returns 1.0 when the union is empty, since two empty sets are identical
+/// 3. Otherwise divides the intersection from `ebm_and_popcount` by the union
+///
+/// # Performance Characteristics
+/// - Zero heap allocations, no intermediate bitmap is built
+/// - Two linear passes over the bitmaps
+/// - Hardware-accelerated when possible (POPCNT instruction)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_tanimoto;
+/// let result = ebm_tanimoto(&[0b1100], &[0b1010]); // 0.3333... (1 shared bit of 3)
+/// let result = ebm_tanimoto(&[0xFF], &[0xFF]); // 1.0
+/// let result = ebm_tanimoto(&[0], &[0]); // 1.0 (two empty fingerprints)
+/// ```
+///
+/// # Function Logic
+/// Tanimoto similarity is the standard metric for comparing chemical fingerprints and other
+/// binary feature sets: `|a & b| / |a | b|`. Identical bitmaps score 1.0 and disjoint ones 0.0.
+/// The empty case would be `0 / 0`, so it is defined as 1.0 to keep the result a valid number.
+///
+/// # Safety Considerations
+/// - Panics when the slices have different lengths
+/// - Never divides by zero
+/// - No undefined behavior possible
+pub fn ebm_tanimoto(a: &[u64], b: &[u64]) -> f64 {
+    let union = ebm_or_popcount(a, b);
+
+    // Two empty bitmaps describe the same (empty) set
+    if union == 0 {
+        return 1.0;
+    }
+    ebm_and_popcount(a, b) as f64 / union as f64
+}
+
+/// Returns the power-of-two bucket a value falls into
+///
+/// # Arguments
+/// * `a` - The value to classify
+///
+/// # Returns
+/// * `u32` - `floor(log2(a))`, with both 0 and 1 in bucket 0
+///
+/// # Implementation Details
+/// This function is the index of the highest set bit with the zero case folded into bucket 0:
+/// 1. Computes the bit length with `ebm_bit_length`
+/// 2. Subtracts one, saturating so that zero (bit length 0) also maps to bucket 0
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One leading-zero count and two subtractions
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_log2_bucket;
+/// let result = ebm_log2_bucket(0u32); // 0
+/// let result = ebm_log2_bucket(1u32); // 0
+/// let result = ebm_log2_bucket(3u32); // 1
+/// let result = ebm_log2_bucket(1000u32); // 9 (512..=1023)
+/// ```
+///
+/// # Function Logic
+/// Bucket `k` holds the values from `2^k` to `2^(k+1) - 1`, so an exponential histogram of
+/// latencies needs only `width` counters. Zero shares bucket 0 with one instead of needing a
+/// bucket of its own. Negative signed values have their sign bit set and land in the top bucket.
+///
+/// # Safety Considerations
+/// - The saturating subtraction cannot underflow
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_log2_bucket<T: EbmInt>(a: T) -> u32 {
+    // The highest set bit, with zero mapped to the same bucket as one
+    ebm_bit_length(a).saturating_sub(1)
+}
+
+/// Returns the number of bits needed to give each of `n` items a distinct index
+///
+/// # Arguments
+/// * `n` - The number of distinct values to index
+///
+/// # Returns
+/// * `u32` - `ceil(log2(n))`, or 0 when `n` is 0 or 1
+///
+/// # Implementation Details
+/// This function measures the largest index instead of taking a logarithm:
+/// 1. Returns 0 when there is at most one value, since a single value needs no bits
+/// 2. Otherwise returns the bit length of the largest index `n - 1` with `ebm_bit_length`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One comparison, one subtraction and one leading-zero count
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_bits_for_values;
+/// let result = ebm_bits_for_values(256); // 8 (indices 0..=255)
+/// let result = ebm_bits_for_values(257); // 9
+/// let result = ebm_bits_for_values(1); // 0
+/// ```
+///
+/// # Function Logic
+/// Sizing an index field for an enum or a table needs room for the largest index, `n - 1`,
+/// which is exactly its bit length. Working on integers avoids the rounding errors of a
+/// floating-point `log2` at exact powers of two.
+///
+/// # Safety Considerations
+/// - The subtraction only runs for `n >= 2`, so it cannot underflow
+/// - No undefined behavior possible
+pub fn ebm_bits_for_values(n: u64) -> u32 {
+    // Zero or one value needs no index bits at all
+    if n <= 1 {
+        return 0;
+    }
+    ebm_bit_length(n - 1)
+}
+
+/// Returns whether the set bits of a value form a single contiguous run
+///
+/// # Arguments
+/// * `a` - The mask to validate
+///
+/// # Returns
+/// * `bool` - `true` if `a` is zero or its set bits are all adjacent
+///
+/// # Implementation Details
+/// This function fills the gap below the run and checks that it reaches a power of two:
+/// 1. Returns `true` for zero, which is the empty field
+/// 2. Sets every bit below the lowest set bit with `a | (a - 1)`
+/// 3. Adds one, which carries through the filled run and leaves a power of two or zero
+///    exactly when there was no hole in the run
+/// 4. Tests that with `x & (x + 1) == 0`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Two wrapping arithmetic operations, one OR, one AND and one comparison
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::analysis::ebm_is_contiguous_mask;
+/// let result = ebm_is_contiguous_mask(0b0011_1100u8); // true
+/// let result = ebm_is_contiguous_mask(0b0011_0100u8); // false
+/// let result = ebm_is_contiguous_mask(0u8); // true
+/// ```
+///
+/// # Function Logic
+/// Register fields are described by contiguous masks, which map directly to an offset and a
+/// width for `ebm_extract_bits` and `ebm_insert_bits`. A mask with a hole would silently split
+/// a field, so it is worth rejecting up front. A run that reaches the top bit makes `x` all
+/// ones and the increment wraps to zero, which is accepted as well.
+///
+/// # Safety Considerations
+/// - Uses wrapping arithmetic, so masks touching the top bit cannot overflow
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_is_contiguous_mask<T: EbmInt>(a: T) -> bool {
+    // The empty mask is trivially a single (empty) run
+    if a == T::ZERO {
+        return true;
+    }
+
+    // Fill below the run, then check that the increment clears all of it
+    let filled = ebmor(a, a.wrapping_sub(T::ONE));
+    ebm_and(filled, filled.wrapping_add(T::ONE)) == T::ZERO
+}
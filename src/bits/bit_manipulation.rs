@@ -0,0 +1,1214 @@
+// Bit Manipulation Operations for Eidolon Math Library
+// This module contains functions that rearrange, extract and construct bit patterns
+// They complement the bitwise operation groups with reversals, masks and permutations
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+
+// Import the integer trait that provides the bit width and intrinsics
+use crate::bits::ebm_int::EbmInt;
+
+// Import the single-bit primitives used for per-bit conversions
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_and::{ebm_clear_bit, ebm_test_bit};
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_or::ebm_set_bit;
+
+// Import the basic operations the field functions are built on
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::{ebm_and, ebmnot, ebmor, ebmxor};
+use crate::bits::bit_operations::bitwise_shifting::bitwise_shifting::{ebm_left_shift, ebm_right_shift};
+
+// Precomputed reversal of every byte value, built at compile time
+const REVERSE_BYTE_TABLE: [u8; 256] = build_reverse_byte_table();
+
+// Builds the byte reversal table by mirroring the bits of every index
+const fn build_reverse_byte_table() -> [u8; 256] {
+    let mut table = [0u8; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut reversed = 0u8;
+        let mut bit = 0;
+        while bit < 8 {
+            if index & (1 << bit) != 0 {
+                reversed |= 1 << (7 - bit);
+            }
+            bit += 1;
+        }
+        table[index] = reversed;
+        index += 1;
+    }
+    table
+}
+
+/// Reverses the order of all bits in a value of generic type T
+///
+/// # Arguments
+/// * `a` - The operand whose bits are reversed
+///
+/// # Returns
+/// * `T` - The value with bit `i` moved to position `width - 1 - i`
+///
+/// # Implementation Details
+/// This function uses the built-in `reverse_bits()` method of the type:
+/// 1. Compiled to a single instruction where available (RBIT on ARM)
+/// 2. Falls back to an optimized shuffle sequence elsewhere
+/// 3. Handles all numeric types uniformly through `EbmInt`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Hardware-accelerated when possible
+/// - Branchless implementation for consistent performance
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_reverse_bits;
+/// let result = ebm_reverse_bits(0b0000_0001u8); // 0b1000_0000
+/// let result = ebm_reverse_bits(0x00F0u16); // 0x0F00
+/// let result = ebm_reverse_bits(1i8); // -128 (bit 0 becomes the sign bit)
+/// ```
+///
+/// # Function Logic
+/// Bit reversal mirrors the binary representation around its center. It is used for FFT index
+/// permutations, CRC computations and converting between MSB-first and LSB-first formats.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in methods
+/// - No undefined behavior possible with valid numeric types
+/// - Compiler ensures type safety at compile time
+pub fn ebm_reverse_bits<T: EbmInt>(a: T) -> T {
+    // Use the built-in reversal, which maps to RBIT where the hardware has it
+    a.reverse_bits()
+}
+
+/// Reverses the order of the bits in a byte using a precomputed lookup table
+///
+/// # Arguments
+/// * `a` - The byte whose bits are reversed
+///
+/// # Returns
+/// * `u8` - The byte with bit `i` moved to position `7 - i`
+///
+/// # Implementation Details
+/// This function replaces the bit shuffle with a single table lookup:
+/// 1. The 256-entry table is computed at compile time
+/// 2. The byte is used directly as the index into the table
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - One memory load from a 256-byte table that stays in L1 cache
+/// - Branchless implementation for consistent performance
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_reverse_bits_u8;
+/// let result = ebm_reverse_bits_u8(0b0000_0001); // 0b1000_0000
+/// let result = ebm_reverse_bits_u8(0b1100_1010); // 0b0101_0011
+/// ```
+///
+/// # Function Logic
+/// Targets without a bit-reversal instruction need several shifts and masks per byte. A lookup
+/// table turns that into one load, which matters when reversing large buffers.
+///
+/// # Safety Considerations
+/// - Uses only safe indexing (a byte is always a valid index into 256 entries)
+/// - No undefined behavior possible
+pub fn ebm_reverse_bits_u8(a: u8) -> u8 {
+    // Every byte value has its reversal stored at its own index
+    REVERSE_BYTE_TABLE[a as usize]
+}
+
+/// Reverses the order of the bits in every byte of a slice, in place
+///
+/// # Arguments
+/// * `data` - The buffer whose bytes are reversed individually
+///
+/// # Implementation Details
+/// This function applies `ebm_reverse_bits_u8` to each byte:
+/// 1. Walks the slice once from start to end
+/// 2. Replaces every byte with its table-based reversal
+/// 3. Keeps the byte order of the buffer unchanged
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Linear time in the length of the buffer
+/// - Cache-friendly sequential access of the buffer
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_reverse_bits_slice;
+/// let mut buffer = [0x01u8, 0x80, 0x0F];
+/// ebm_reverse_bits_slice(&mut buffer); // [0x80, 0x01, 0xF0]
+/// ```
+///
+/// # Function Logic
+/// Display drivers and some serial peripherals expect LSB-first bytes. Reversing each byte in
+/// place converts an MSB-first buffer without allocating a copy.
+///
+/// # Safety Considerations
+/// - Uses only safe iteration over the mutable slice
+/// - No undefined behavior possible
+pub fn ebm_reverse_bits_slice(data: &mut [u8]) {
+    // Reverse every byte through the lookup table
+    for byte in data.iter_mut() {
+        *byte = ebm_reverse_bits_u8(*byte);
+    }
+}
+
+/// Reverses the order of the low `n` bits of a value
+///
+/// # Arguments
+/// * `a` - The value whose low bits are reversed
+/// * `n` - The number of low bits taking part in the reversal
+///
+/// # Returns
+/// * `T` - The low `n` bits of `a` in reverse order, with every higher bit cleared
+///
+/// # Implementation Details
+/// This function reuses the full-width reversal:
+/// 1. Returns zero for an empty field, which would otherwise need a full-width shift
+/// 2. Reverses all bits with `ebm_reverse_bits`, moving the field to the top
+/// 3. Shifts it back down by `width - n` and masks away sign-fill bits with `ebm_low_mask`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One reversal, one shift and one AND
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_reverse_bits_n;
+/// let result = ebm_reverse_bits_n(0b001u8, 3); // 0b100
+/// let result = ebm_reverse_bits_n(0b110u8, 3); // 0b011
+/// let result = ebm_reverse_bits_n(0xF1u8, 4); // 0b1000 (the high nibble is dropped)
+/// ```
+///
+/// # Function Logic
+/// FFT index permutations and bit-serial protocols reverse fields narrower than the type. The
+/// reversal is taken within the field, so bit `i` moves to position `n - 1 - i`.
+///
+/// # Safety Considerations
+/// - `n` must not exceed the bit width (checked with a debug assertion)
+/// - Never shifts by the full width or more
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_reverse_bits_n<T: EbmInt>(a: T, n: u32) -> T {
+    // The field has to fit in the type
+    debug_assert!(n <= T::BITS, "field width exceeds the type width");
+
+    // An empty field reverses to nothing
+    if n == 0 {
+        return T::ZERO;
+    }
+
+    // Reverse everything, then bring the field back down to bit 0
+    ebm_and(ebm_right_shift(ebm_reverse_bits(a), T::BITS - n), ebm_low_mask(n))
+}
+
+/// Reverses the order of fixed-size bit groups while keeping the bits inside each group in place
+///
+/// # Arguments
+/// * `a` - The value whose groups are reordered
+/// * `group` - The number of bits per group, which must divide the bit width
+///
+/// # Returns
+/// * `T` - The value with group `i` moved to position `width / group - 1 - i`
+///
+/// # Implementation Details
+/// This function moves each group with the bit field primitives:
+/// 1. Computes the number of groups from the bit width
+/// 2. Reads group `i` with `ebm_extract_bits`
+/// 3. Writes it into the mirrored slot of the result with `ebm_insert_bits`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One extract and one insert per group
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_reverse_groups;
+/// let result = ebm_reverse_groups(0x1234u16, 4); // 0x4321 (nibble order reversed)
+/// let result = ebm_reverse_groups(0x1234_5678u32, 8); // 0x7856_3412 (byte order reversed)
+/// let result = ebm_reverse_groups(0b1011u8, 1); // 0b1101_0000 (plain bit reversal)
+/// ```
+///
+/// # Function Logic
+/// This generalizes the familiar reversals: a group of 1 reverses the bits, a group of 8 swaps
+/// the bytes and a group equal to the width leaves the value unchanged. Bit-permutation networks
+/// use the intermediate sizes as stages between them.
+///
+/// # Safety Considerations
+/// - `group` must be between 1 and the bit width and divide it (checked with a debug assertion)
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_reverse_groups<T: EbmInt>(a: T, group: u32) -> T {
+    // The groups have to tile the type exactly; every width is a power of two, so its divisors are too
+    debug_assert!(group <= T::BITS && group.is_power_of_two(), "group size must divide the bit width");
+
+    // Move every group into its mirrored slot
+    let count = T::BITS / group;
+    (0..count).fold(T::ZERO, |result, index| {
+        ebm_insert_bits(result, ebm_extract_bits(a, index * group, group), (count - 1 - index) * group, group)
+    })
+}
+
+/// Expands every bit of a value into a full byte mask, least significant bit first
+///
+/// # Arguments
+/// * `a` - The predicate word whose bits are expanded
+///
+/// # Returns
+/// * `Vec<u8>` - One byte per bit of `T`: 0xFF where the bit is set, 0x00 where it is clear
+///
+/// # Implementation Details
+/// This function walks the bits from position 0 upward:
+/// 1. Allocates one output byte per bit of the type
+/// 2. Tests every bit with `ebm_test_bit`
+/// 3. Writes 0xFF for set bits and 0x00 for clear bits
+///
+/// # Performance Characteristics
+/// - One heap allocation of `width` bytes
+/// - Linear time in the bit width
+/// - Sequential writes into the output buffer
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_bits_to_bytes;
+/// let mask = ebm_bits_to_bytes(0b0000_0101u8); // [0xFF, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00]
+/// ```
+///
+/// # Function Logic
+/// Byte-wise SIMD blends select lanes with full-byte masks. This converts a compact predicate
+/// word into such a lane mask, with output byte `i` corresponding to bit `i`.
+///
+/// # Safety Considerations
+/// - Uses only safe operations
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_bits_to_bytes<T: EbmInt>(a: T) -> Vec<u8> {
+    // Map every bit to a full lane of ones or zeros
+    (0..T::BITS)
+        .map(|index| if ebm_test_bit(a, index) { 0xFF } else { 0x00 })
+        .collect()
+}
+
+/// Packs the high bit of every byte into an integer, first byte into the least significant bit
+///
+/// # Arguments
+/// * `bytes` - The lane mask to pack, at most one byte per bit of `T`
+///
+/// # Returns
+/// * `T` - A value whose bit `i` is the most significant bit of `bytes[i]`
+///
+/// # Implementation Details
+/// This function is the inverse of `ebm_bits_to_bytes`:
+/// 1. Checks that the mask fits into the target type with a debug assertion
+/// 2. Reads the most significant bit of every byte
+/// 3. Sets the matching bit of the result with `ebm_set_bit`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Linear time in the length of the mask
+/// - Sequential reads of the input buffer
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_bytes_to_bits;
+/// let result: u8 = ebm_bytes_to_bits(&[0xFF, 0x00, 0x80, 0x7F]); // 0b0000_0101
+/// ```
+///
+/// # Function Logic
+/// Only the high bit of each byte is used, which matches the convention of SIMD move-mask
+/// instructions such as PMOVMSKB. Missing trailing bytes leave the upper bits clear.
+///
+/// # Safety Considerations
+/// - `bytes` must not be longer than the bit width (checked with a debug assertion)
+/// - Uses only safe operations
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_bytes_to_bits<T: EbmInt>(bytes: &[u8]) -> T {
+    // Every byte needs a bit position in the result
+    debug_assert!(bytes.len() <= T::BITS as usize, "more bytes than bits in the target type");
+
+    // Collect the high bit of every byte into its matching position
+    let mut result = T::ZERO;
+    for (index, &byte) in bytes.iter().enumerate() {
+        if byte & 0x80 != 0 {
+            result = ebm_set_bit(result, index as u32);
+        }
+    }
+    result
+}
+
+/// Builds a mask with the low `count` bits set
+///
+/// # Arguments
+/// * `count` - The number of low bits to set
+///
+/// # Returns
+/// * `T` - A value with bits `0..count` set, or all bits set when `count >= width`
+///
+/// # Implementation Details
+/// This function avoids the shift-by-width overflow of the usual `(1 << count) - 1` idiom:
+/// 1. Returns all ones directly when `count` covers the whole type
+/// 2. Otherwise shifts one to position `count` and subtracts one with wrapping arithmetic
+/// 3. The wrapping subtraction keeps `count = width - 1` correct for signed types
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One comparison, one shift and one subtraction
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_low_mask;
+/// let result: u8 = ebm_low_mask(3); // 0b0000_0111
+/// let result: u8 = ebm_low_mask(8); // 0xFF
+/// let result: u8 = ebm_low_mask(0); // 0
+/// ```
+///
+/// # Function Logic
+/// Low masks select bit fields that start at position 0. Every field operation in this module
+/// is built from them, so the full-width case has to be handled without an overflowing shift.
+///
+/// # Safety Considerations
+/// - Never shifts by the full width or more
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_low_mask<T: EbmInt>(count: u32) -> T {
+    // A mask covering the whole type cannot be built with a shift
+    if count >= T::BITS {
+        return !T::ZERO;
+    }
+
+    // Everything below the shifted one is set after subtracting one
+    ebm_left_shift(T::ONE, count).wrapping_sub(T::ONE)
+}
+
+/// Builds a value with the low `count` bits set
+///
+/// # Arguments
+/// * `count` - The number of low bits to set
+///
+/// # Returns
+/// * `T` - A value with bits `0..count` set, zero for `count == 0` and all ones for
+///   `count >= width`
+///
+/// # Implementation Details
+/// This function is a named entry point for `ebm_low_mask`:
+/// 1. Forwards the count unchanged
+/// 2. Relies on its full-width check, so `count >= width` never reaches an overflowing shift
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Inlined into the same comparison, shift and subtraction as `ebm_low_mask`
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_ones;
+/// let result: u16 = ebm_ones(5); // 0b1_1111
+/// let result: u16 = ebm_ones(16); // 0xFFFF
+/// let result: u16 = ebm_ones(40); // 0xFFFF
+/// ```
+///
+/// # Function Logic
+/// The obvious `(1 << count) - 1` overflows when `count` equals the bit width, which is exactly
+/// the case of a mask covering the whole type. Reading a mask as "this many ones" is common
+/// enough to deserve its own name, and routing it through `ebm_low_mask` keeps that edge case
+/// handled in one place.
+///
+/// # Safety Considerations
+/// - Never shifts by the full width or more
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_ones<T: EbmInt>(count: u32) -> T {
+    // Same mask, named after the number of ones it holds
+    ebm_low_mask(count)
+}
+
+/// Clears every bit at or above a position
+///
+/// # Arguments
+/// * `a` - The value to truncate
+/// * `bits` - The number of low bits to keep
+///
+/// # Returns
+/// * `T` - The low `bits` bits of `a`; zero for `bits == 0` and `a` unchanged for `bits >= width`
+///
+/// # Implementation Details
+/// This function masks the value with a low mask:
+/// 1. Builds the mask with `ebm_low_mask`, which handles the empty and full-width cases
+/// 2. ANDs the value with the mask
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - The mask construction plus one AND (a single BZHI instruction on some targets)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_truncate_to_bits;
+/// let result = ebm_truncate_to_bits(0xFFu8, 4); // 0x0F
+/// let result = ebm_truncate_to_bits(0xFFu8, 0); // 0
+/// let result = ebm_truncate_to_bits(0xABu8, 8); // 0xAB
+/// ```
+///
+/// # Function Logic
+/// Packing a value into a fixed-width field must not let excess high bits spill into the
+/// neighboring fields. Truncating first enforces the field width, and since the mask never
+/// needs a full-width shift, any `bits` value is safe.
+///
+/// # Safety Considerations
+/// - Never shifts by the full width or more
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_truncate_to_bits<T: EbmInt>(a: T, bits: u32) -> T {
+    // Keep only the bits below the cut
+    ebm_and(a, ebm_low_mask::<T>(bits))
+}
+
+/// Extracts a bit field of `width` bits starting at `offset`
+///
+/// # Arguments
+/// * `a` - The value containing the field
+/// * `offset` - The position of the lowest bit of the field
+/// * `width` - The number of bits in the field
+///
+/// # Returns
+/// * `T` - The field value, moved down to bit 0 with all higher bits cleared
+///
+/// # Implementation Details
+/// This function isolates the field with a shift and a mask:
+/// 1. Shifts the value right so the field starts at bit 0
+/// 2. Masks the result with `ebm_low_mask(width)`, which also removes sign-fill bits
+/// 3. Checks that the field lies inside the type with a debug assertion
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One shift and one AND (a single BEXTR instruction on some targets)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_extract_bits;
+/// let result = ebm_extract_bits(0b1101_0110u8, 2, 3); // 0b101
+/// let result = ebm_extract_bits(0xABCDu16, 4, 8); // 0xBC
+/// ```
+///
+/// # Function Logic
+/// Packed hardware registers and binary formats store several values side by side. Extracting a
+/// field returns one of them as a plain number.
+///
+/// # Safety Considerations
+/// - `offset + width` must not exceed the bit width (checked with a debug assertion)
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_extract_bits<T: EbmInt>(a: T, offset: u32, width: u32) -> T {
+    // The field has to lie completely inside the type
+    debug_assert!(offset < T::BITS && width <= T::BITS - offset, "bit field out of range");
+
+    // Move the field down to bit 0 and drop everything above it
+    ebm_and(ebm_right_shift(a, offset), ebm_low_mask::<T>(width))
+}
+
+/// Replaces a bit field of `width` bits starting at `offset` with a new value
+///
+/// # Arguments
+/// * `a` - The value containing the field
+/// * `value` - The new field value, taken from its low `width` bits
+/// * `offset` - The position of the lowest bit of the field
+/// * `width` - The number of bits in the field
+///
+/// # Returns
+/// * `T` - `a` with the field replaced and every other bit unchanged
+///
+/// # Implementation Details
+/// This function merges the new field into the value with masks:
+/// 1. Builds the field mask by shifting `ebm_low_mask(width)` to `offset`
+/// 2. Clears the field in `a` with the inverted mask
+/// 3. Shifts the new value into place and masks off any excess bits
+/// 4. Combines both parts with `ebmor`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - A few shifts and masks, fully branchless
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_insert_bits;
+/// let result = ebm_insert_bits(0xFFu8, 0b010, 2, 3); // 0b1110_1011
+/// let result = ebm_insert_bits(0x0000u16, 0xAB, 4, 8); // 0x0AB0
+/// ```
+///
+/// # Function Logic
+/// This is the write side of `ebm_extract_bits`. Bits of `value` above `width` are discarded so
+/// an oversized value can never spill into neighboring fields.
+///
+/// # Safety Considerations
+/// - `offset + width` must not exceed the bit width (checked with a debug assertion)
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_insert_bits<T: EbmInt>(a: T, value: T, offset: u32, width: u32) -> T {
+    // The field has to lie completely inside the type
+    debug_assert!(offset < T::BITS && width <= T::BITS - offset, "bit field out of range");
+
+    // Clear the field, then merge in the new value restricted to the field
+    let field_mask = ebm_left_shift(ebm_low_mask::<T>(width), offset);
+    let cleared = ebm_and(a, ebmnot(field_mask));
+    ebmor(cleared, ebm_and(ebm_left_shift(value, offset), field_mask))
+}
+
+/// Exchanges the bits at two positions
+///
+/// # Arguments
+/// * `a` - The value whose bits are swapped
+/// * `i` - The position of the first bit
+/// * `j` - The position of the second bit
+///
+/// # Returns
+/// * `T` - `a` with the bits at `i` and `j` exchanged and every other bit unchanged
+///
+/// # Implementation Details
+/// This function uses the XOR-swap trick:
+/// 1. Moves both bits down to bit 0 and compares them with `ebmxor`
+/// 2. Returns the value unchanged when the bits are equal
+/// 3. Otherwise flips both bits with a mask of `(1 << i) | (1 << j)`
+/// 4. Flipping two different bits is the same as exchanging them
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - A handful of shifts and XORs, no loops
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_swap_bits;
+/// let result = ebm_swap_bits(0x01u8, 0, 7); // 0x80
+/// let result = ebm_swap_bits(0b0110u8, 1, 3); // 0b1100
+/// let result = ebm_swap_bits(0x01u8, 0, 0); // 0x01 (swapping a bit with itself)
+/// ```
+///
+/// # Function Logic
+/// Only the case where the two bits differ changes the value, and in that case exchanging them
+/// is the same as inverting both. This avoids extracting and reinserting the individual bits.
+///
+/// # Safety Considerations
+/// - `i` and `j` must be below the bit width (checked with a debug assertion)
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_swap_bits<T: EbmInt>(a: T, i: u32, j: u32) -> T {
+    // Both positions have to lie inside the type
+    debug_assert!(i < T::BITS && j < T::BITS, "bit index out of range");
+
+    // Compare the two bits at position 0
+    let differ = ebm_and(ebmxor(ebm_right_shift(a, i), ebm_right_shift(a, j)), T::ONE);
+
+    // Equal bits make the swap a no-op
+    if differ == T::ZERO {
+        return a;
+    }
+
+    // Flipping two different bits exchanges them
+    ebmxor(a, ebmor(ebm_left_shift(T::ONE, i), ebm_left_shift(T::ONE, j)))
+}
+
+/// Applies an arbitrary bit permutation
+///
+/// # Arguments
+/// * `a` - The value whose bits are permuted
+/// * `perm` - The wiring table, where `perm[k]` is the source bit position for output bit `k`
+///
+/// # Returns
+/// * `T` - The permuted value; output bits at positions `perm.len()` and above are zero
+///
+/// # Implementation Details
+/// This function routes every output bit from its source position:
+/// 1. Starts from zero
+/// 2. Reads source bit `perm[k]` with `ebm_test_bit`
+/// 3. Sets output bit `k` with `ebm_set_bit` when the source bit is set
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One test and at most one set per table entry
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_permute_bits;
+/// let result = ebm_permute_bits(0b0000_0001u8, &[7, 6, 5, 4, 3, 2, 1, 0]); // 0x80 (reversal)
+/// let result = ebm_permute_bits(0b0000_0110u8, &[1, 2]); // 0b11 (gathers bits 1 and 2)
+/// ```
+///
+/// # Function Logic
+/// Fixed wiring tables such as the DES permutations describe where every output bit comes
+/// from. Indexing the table by output position allows it to select, duplicate or drop bits,
+/// so it also covers expansion and compression tables.
+///
+/// # Safety Considerations
+/// - Panics if the table has more entries than the bit width
+/// - Panics if any source position is outside the bit width
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_permute_bits<T: EbmInt>(a: T, perm: &[u32]) -> T {
+    // Every output bit has to exist in the type
+    assert!(perm.len() as u32 <= T::BITS, "permutation table is wider than the type");
+
+    // Route each source bit to its output position
+    let mut result = T::ZERO;
+    for (output, &source) in perm.iter().enumerate() {
+        assert!(source < T::BITS, "permutation source bit out of range");
+        if ebm_test_bit(a, source) {
+            result = ebm_set_bit(result, output as u32);
+        }
+    }
+    result
+}
+
+/// Copies a byte into all eight bytes of a `u64`
+///
+/// # Arguments
+/// * `b` - The byte to replicate
+///
+/// # Returns
+/// * `u64` - A word whose eight bytes all equal `b`
+///
+/// # Implementation Details
+/// This function replicates the byte with a single multiplication:
+/// 1. Widens the byte to `u64`
+/// 2. Multiplies it by `0x0101010101010101`, which has a one in the lowest bit of every byte
+/// 3. The partial products land in separate bytes, so no carries occur
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - A single multiplication
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_broadcast_byte;
+/// let result = ebm_broadcast_byte(0xAB); // 0xABABABABABABABAB
+/// let result = ebm_broadcast_byte(0x01); // 0x0101010101010101
+/// ```
+///
+/// # Function Logic
+/// SWAR code treats a word as eight byte lanes and needs lane-wise constants such as a search
+/// needle or a per-byte bias. Multiplying by the lane-one pattern builds them in one step.
+///
+/// # Safety Considerations
+/// - The product never exceeds `u64::MAX`, so no overflow is possible
+/// - No undefined behavior possible
+pub fn ebm_broadcast_byte(b: u8) -> u64 {
+    // One partial product per byte lane, none of which overlap
+    b as u64 * 0x0101_0101_0101_0101
+}
+
+/// Copies a byte into every byte of any integer type
+///
+/// # Arguments
+/// * `b` - The byte to replicate
+///
+/// # Returns
+/// * `T` - A value whose bytes all equal `b`
+///
+/// # Implementation Details
+/// This function builds the value one byte lane at a time:
+/// 1. Starts with the byte converted to `T`
+/// 2. Shifts the value up by eight bits and ORs in the byte once per remaining lane
+/// 3. Never shifts by more than eight bits, so single-byte types need no special case
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - `width / 8 - 1` shift-and-OR steps, folded to a constant for constant inputs
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_broadcast;
+/// let result: u32 = ebm_broadcast(0xAB); // 0xABABABAB
+/// let result: u8 = ebm_broadcast(0x5A); // 0x5A
+/// let result: i16 = ebm_broadcast(0xFF); // -1
+/// ```
+///
+/// # Function Logic
+/// This is the width-generic form of `ebm_broadcast_byte`. It works for `u128` and for signed
+/// types, where the replicated pattern is reinterpreted as a two's-complement value.
+///
+/// # Safety Considerations
+/// - Never shifts by the full width or more
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_broadcast<T: EbmInt>(b: u8) -> T {
+    let byte = T::from_u32(b as u32);
+    let mut result = byte;
+
+    // Append the byte to every remaining lane
+    for _ in 1..T::BITS / 8 {
+        result = ebmor(ebm_left_shift(result, 8u32), byte);
+    }
+    result
+}
+
+/// Increments a bit-reversed counter by adding one at the top of a `width`-bit field
+///
+/// # Arguments
+/// * `a` - The current counter value, held in the low `width` bits
+/// * `width` - The number of bits in the counter
+///
+/// # Returns
+/// * `T` - The next counter value, wrapping to zero after all ones
+///
+/// # Implementation Details
+/// This function performs a binary increment with the carry running toward the LSB:
+/// 1. Starts at bit `width - 1`, the lowest digit of the reversed counter
+/// 2. Clears every set bit it passes with `ebm_clear_bit`, which propagates the carry
+/// 3. Sets the first clear bit with `ebm_set_bit` and stops
+/// 4. Returns zero when every bit of the field was set
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Amortized two bit operations per increment, at most `width`
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_reverse_increment;
+/// let result = ebm_reverse_increment(0u8, 3); // 4 (0b000 -> 0b100)
+/// let result = ebm_reverse_increment(4u8, 3); // 2 (0b100 -> 0b010)
+/// let result = ebm_reverse_increment(7u8, 3); // 0 (wraps around)
+/// ```
+///
+/// # Function Logic
+/// Counting in this order visits `0, 4, 2, 6, 1, 5, 3, 7` for three bits, which is the
+/// bit-reversed index sequence of an FFT. Incrementing the reversed value directly avoids
+/// reversing every index. Bits at and above `width` are left unchanged.
+///
+/// # Safety Considerations
+/// - `width` must be between 1 and the bit width (checked with a debug assertion)
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_reverse_increment<T: EbmInt>(a: T, width: u32) -> T {
+    // The counter has to fit in the type
+    debug_assert!(width >= 1 && width <= T::BITS, "counter width out of range");
+
+    // Propagate the carry from the top of the field toward bit 0
+    let mut result = a;
+    for index in (0..width).rev() {
+        if ebm_test_bit(result, index) {
+            result = ebm_clear_bit(result, index);
+        } else {
+            return ebm_set_bit(result, index);
+        }
+    }
+
+    // Every bit carried out, so the counter wrapped to zero
+    result
+}
+
+/// Builds the bit-reversal permutation of all `bits`-bit indices
+///
+/// # Arguments
+/// * `bits` - The number of index bits, so the table has `2^bits` entries
+///
+/// # Returns
+/// * `Vec<u32>` - Entry `i` holds `i` with its low `bits` bits reversed
+///
+/// # Implementation Details
+/// This function reverses every index once:
+/// 1. Allocates a table of `2^bits` entries
+/// 2. Fills entry `i` with `ebm_reverse_bits_n(i, bits)`
+///
+/// # Performance Characteristics
+/// - One heap allocation of `2^bits` entries
+/// - Linear time in the table size
+/// - Sequential writes into the output buffer
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_bit_reversal_table;
+/// let table = ebm_bit_reversal_table(3); // [0, 4, 2, 6, 1, 5, 3, 7]
+/// let table = ebm_bit_reversal_table(0); // [0]
+/// ```
+///
+/// # Function Logic
+/// An in-place radix-2 FFT reorders its input into bit-reversed index order. Building the
+/// permutation once lets every transform of the same size apply it with plain lookups. The
+/// table is its own inverse, so it also undoes the reordering.
+///
+/// # Safety Considerations
+/// - `bits` must be less than 32 (checked with a debug assertion)
+/// - Large `bits` values allocate exponentially large tables
+/// - No undefined behavior possible
+pub fn ebm_bit_reversal_table(bits: u32) -> Vec<u32> {
+    // Every index has to fit in a u32
+    debug_assert!(bits < u32::BITS, "index width out of range");
+
+    // Reverse each index within the field
+    (0..1u32 << bits).map(|index| ebm_reverse_bits_n(index, bits)).collect()
+}
+
+/// Increments a bit-reversed counter that spans several 64-bit limbs
+///
+/// # Arguments
+/// * `limbs` - The counter, least significant limb first, with bit `i` in limb `i / 64`
+/// * `total_bits` - The number of bits in the counter
+///
+/// # Implementation Details
+/// This function performs the reverse-carry increment of `ebm_reverse_increment` across limbs:
+/// 1. Starts at bit `total_bits - 1`, the lowest digit of the reversed counter
+/// 2. Clears every set bit it passes with `ebm_clear_bit`, which propagates the carry
+/// 3. Sets the first clear bit with `ebm_set_bit` and stops
+/// 4. Leaves the counter at zero when every bit of it was set
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Amortized two bit operations per increment, at most `total_bits`
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_reverse_increment_wide;
+/// let mut counter = [0u64, 0];
+/// ebm_reverse_increment_wide(&mut counter, 70); // [0, 1 << 5] (bit 69 set)
+/// ebm_reverse_increment_wide(&mut counter, 70); // [0, 1 << 4] (bit 68 set)
+/// ```
+///
+/// # Function Logic
+/// Bit-reversed index generation for transforms larger than `2^64` points needs the counter
+/// to cross limb boundaries. The carry moves from the top bit toward bit 0 exactly as in the
+/// single-word version, only with the bit position split into a limb and an offset. Bits at
+/// and above `total_bits` are left unchanged.
+///
+/// # Safety Considerations
+/// - `total_bits` must be at least 1 and fit in the limbs (checked with a debug assertion)
+/// - Uses only safe indexing into the slice
+/// - No undefined behavior possible
+pub fn ebm_reverse_increment_wide(limbs: &mut [u64], total_bits: u32) {
+    // The counter has to fit in the limbs
+    debug_assert!(total_bits >= 1 && total_bits as usize <= limbs.len() * 64, "counter width out of range");
+
+    // Propagate the carry from the top of the counter toward bit 0
+    for index in (0..total_bits).rev() {
+        let limb = &mut limbs[(index / 64) as usize];
+        if ebm_test_bit(*limb, index % 64) {
+            *limb = ebm_clear_bit(*limb, index % 64);
+        } else {
+            *limb = ebm_set_bit(*limb, index % 64);
+            return;
+        }
+    }
+}
+
+/// Returns whether exactly one bit is set
+///
+/// # Arguments
+/// * `a` - The value to test
+///
+/// # Returns
+/// * `bool` - `true` if `a` has exactly one set bit
+///
+/// # Implementation Details
+/// This function uses the clear-lowest-bit identity:
+/// 1. Rejects zero, which has no set bit
+/// 2. Clears the lowest set bit with `a & (a - 1)`, using wrapping subtraction
+/// 3. Reports a single bit when nothing is left
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One subtraction, one AND and two comparisons
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_is_single_bit;
+/// let result = ebm_is_single_bit(0b0100u8); // true
+/// let result = ebm_is_single_bit(0b0110u8); // false
+/// let result = ebm_is_single_bit(0u8); // false
+/// ```
+///
+/// # Function Logic
+/// Subtracting one flips the lowest set bit and every zero below it, so the AND removes exactly
+/// that bit. For unsigned types this is the power-of-two test; the name reflects its use for
+/// validating that a flag value names exactly one variant. For signed types the sign bit alone
+/// also counts as a single bit.
+///
+/// # Safety Considerations
+/// - Uses wrapping subtraction, so `T::MIN` of signed types cannot overflow
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_is_single_bit<T: EbmInt>(a: T) -> bool {
+    // Clearing the lowest set bit must leave nothing behind
+    a != T::ZERO && ebm_and(a, a.wrapping_sub(T::ONE)) == T::ZERO
+}
+
+/// Isolates the lowest set bit
+///
+/// # Arguments
+/// * `a` - The value to inspect
+///
+/// # Returns
+/// * `T` - A mask containing only the lowest set bit of `a`, or zero when `a` is zero
+///
+/// # Implementation Details
+/// This function uses the two's-complement isolation identity:
+/// 1. Negates the value with wrapping negation, which flips every bit above the lowest set bit
+/// 2. ANDs the negation with the value, leaving only the lowest set bit
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One negation and one AND (a single BLSI instruction on some targets)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_lowest_set_mask;
+/// let result = ebm_lowest_set_mask(0b0010_1000u8); // 0b0000_1000
+/// let result = ebm_lowest_set_mask(0u8); // 0
+/// ```
+///
+/// # Function Logic
+/// In a priority encoder the lowest set bit is the request that wins. `-a` equals `!a + 1`, and
+/// the increment carries through the inverted trailing zeros into the lowest set bit, so that
+/// bit is the only one `a` and `-a` share.
+///
+/// # Safety Considerations
+/// - Uses wrapping negation, so `T::MIN` of signed types cannot overflow
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_lowest_set_mask<T: EbmInt>(a: T) -> T {
+    // The lowest set bit is the only bit shared with the negation
+    ebm_and(a, a.wrapping_neg())
+}
+
+/// Builds a mask of all bits strictly below the lowest set bit
+///
+/// # Arguments
+/// * `a` - The value to inspect
+///
+/// # Returns
+/// * `T` - A mask of the trailing zero positions of `a`, or all ones when `a` is zero
+///
+/// # Implementation Details
+/// This function uses the trailing-zero mask identity `(a - 1) & !a`:
+/// 1. Subtracts one with wrapping arithmetic, which turns the trailing zeros into ones
+/// 2. ANDs the result with `ebmnot(a)` to drop the lowest set bit and everything above it
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One subtraction, one NOT and one AND (a single TZMSK instruction on some targets)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_below_lowest_set;
+/// let result = ebm_below_lowest_set(0b0010_1000u8); // 0b0000_0111
+/// let result = ebm_below_lowest_set(0b0000_0001u8); // 0
+/// let result = ebm_below_lowest_set(0u8); // 0xFF
+/// ```
+///
+/// # Function Logic
+/// The mask selects every position with a higher priority than the winning request of a
+/// priority encoder. Zero has no set bit, so every position counts as below it.
+///
+/// # Safety Considerations
+/// - Uses wrapping subtraction, so zero and `T::MIN` cannot overflow
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_below_lowest_set<T: EbmInt>(a: T) -> T {
+    // The decrement turns exactly the trailing zeros into ones
+    ebm_and(a.wrapping_sub(T::ONE), ebmnot(a))
+}
+
+/// Isolates the lowest set bit (BMI1 BLSI)
+///
+/// # Arguments
+/// * `a` - The value to inspect
+///
+/// # Returns
+/// * `T` - A mask containing only the lowest set bit of `a`, or zero when `a` is zero
+///
+/// # Implementation Details
+/// This function is the BMI1 name for `ebm_lowest_set_mask`:
+/// 1. Forwards the value unchanged
+/// 2. Computes `a & -a` with wrapping negation
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One negation and one AND (a single BLSI instruction on some targets)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_blsi;
+/// let result = ebm_blsi(0b0101_0000u8); // 0b0001_0000
+/// let result = ebm_blsi(0u8); // 0
+/// ```
+///
+/// # Function Logic
+/// Code ported from x86 intrinsics reads most naturally with the instruction names. Together
+/// with `ebm_blsr` and `ebm_blsmsk` this completes the BMI1 lowest-set-bit family.
+///
+/// # Safety Considerations
+/// - Uses wrapping negation, so `T::MIN` of signed types cannot overflow
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_blsi<T: EbmInt>(a: T) -> T {
+    // Same operation as the priority encoder helper
+    ebm_lowest_set_mask(a)
+}
+
+/// Clears the lowest set bit (BMI1 BLSR)
+///
+/// # Arguments
+/// * `a` - The value to modify
+///
+/// # Returns
+/// * `T` - `a` with its lowest set bit cleared, or zero when `a` is zero
+///
+/// # Implementation Details
+/// This function uses the clear-lowest-bit identity `a & (a - 1)`:
+/// 1. Subtracts one with wrapping arithmetic, which flips the lowest set bit and the zeros below it
+/// 2. ANDs the result with `a`, keeping every bit above the lowest set bit
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One subtraction and one AND (a single BLSR instruction on some targets)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_blsr;
+/// let result = ebm_blsr(0b0101_0000u8); // 0b0100_0000
+/// let result = ebm_blsr(0u8); // 0
+/// ```
+///
+/// # Function Logic
+/// Repeating the reset visits the set bits from the lowest upward, which is the usual way to
+/// loop over the members of a bit set in O(set bits).
+///
+/// # Safety Considerations
+/// - Uses wrapping subtraction, so zero and `T::MIN` cannot overflow
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_blsr<T: EbmInt>(a: T) -> T {
+    // The decrement only changes the lowest set bit and the zeros below it
+    ebm_and(a, a.wrapping_sub(T::ONE))
+}
+
+/// Builds a mask up to and including the lowest set bit (BMI1 BLSMSK)
+///
+/// # Arguments
+/// * `a` - The value to inspect
+///
+/// # Returns
+/// * `T` - A mask of the lowest set bit and every position below it, or all ones when `a` is zero
+///
+/// # Implementation Details
+/// This function uses the identity `a ^ (a - 1)`:
+/// 1. Subtracts one with wrapping arithmetic, which flips the lowest set bit and the zeros below it
+/// 2. XORs the result with `a`, leaving exactly the flipped positions set
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One subtraction and one XOR (a single BLSMSK instruction on some targets)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_blsmsk;
+/// let result = ebm_blsmsk(0b0101_0000u8); // 0b0001_1111
+/// let result = ebm_blsmsk(0b0000_0001u8); // 0b0000_0001
+/// let result = ebm_blsmsk(0u8); // 0xFF
+/// ```
+///
+/// # Function Logic
+/// When the low bits of a word are processed up to the first set bit, this mask covers exactly
+/// the processed part, so `a & !ebm_blsmsk(a)` removes it. It equals `ebm_below_lowest_set`
+/// plus the lowest set bit itself. Zero flips every bit on the decrement, so the mask is full.
+///
+/// # Safety Considerations
+/// - Uses wrapping subtraction, so zero and `T::MIN` cannot overflow
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_blsmsk<T: EbmInt>(a: T) -> T {
+    // The decrement flips exactly the lowest set bit and everything below it
+    ebmxor(a, a.wrapping_sub(T::ONE))
+}
+
+/// Deposits the low bits of a value into the set positions of a mask (software PDEP)
+///
+/// # Arguments
+/// * `src` - The packed value whose low bits are distributed
+/// * `mask` - The positions that receive the bits, filled from the lowest set bit upward
+///
+/// # Returns
+/// * `T` - A value with bit `k` of `src` placed at the `k`-th lowest set bit of `mask`
+///
+/// # Implementation Details
+/// This function walks the set bits of the mask from the lowest upward:
+/// 1. Isolates the lowest remaining mask bit with `ebm_lowest_set_mask`
+/// 2. Copies the next source bit there when it is set, tested with `ebm_test_bit`
+/// 3. Removes the mask bit and moves to the next source bit
+/// 4. Source bits beyond the number of mask bits are dropped
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One iteration per set bit of the mask, not per bit of the type
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_pdep;
+/// let result = ebm_pdep(0b11u8, 0b1010_0000); // 0b1010_0000
+/// let result = ebm_pdep(0b101u8, 0b0111_0000); // 0b0101_0000
+/// let result = ebm_pdep(0b1111u8, 0b0000_0011); // 0b11 (the upper source bits are lost)
+/// ```
+///
+/// # Function Logic
+/// This is the operation of the x86 BMI2 PDEP instruction: it scatters a packed value into a
+/// sparse field layout. It is the inverse of gathering the mask positions back into a packed
+/// value.
+///
+/// # Safety Considerations
+/// - Uses wrapping negation, so signed masks cannot overflow
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_pdep<T: EbmInt>(src: T, mask: T) -> T {
+    let mut result = T::ZERO;
+    let mut remaining = mask;
+    let mut source = 0;
+
+    // Fill the mask positions one by one from the bottom
+    while remaining != T::ZERO {
+        let target = ebm_lowest_set_mask(remaining);
+        if ebm_test_bit(src, source) {
+            result = ebmor(result, target);
+        }
+        remaining = ebmxor(remaining, target);
+        source += 1;
+    }
+    result
+}
+
+/// Deposits the low bits of a value into a mask, failing when any set bit would be lost
+///
+/// # Arguments
+/// * `src` - The packed value whose bits are distributed
+/// * `mask` - The positions that receive the bits
+///
+/// # Returns
+/// * `Option<T>` - The result of `ebm_pdep`, or `None` when `src` does not fit in the mask
+///
+/// # Implementation Details
+/// This function checks the capacity before depositing:
+/// 1. Counts the mask bits, which is the number of source bits the layout can hold
+/// 2. Rejects the value when any set bit of `src` lies at or above that count
+/// 3. Otherwise deposits it with `ebm_pdep`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One population count and one mask test on top of `ebm_pdep`
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_manipulation::ebm_try_pdep;
+/// let result = ebm_try_pdep(0b11u8, 0b1010_0000); // Some(0b1010_0000)
+/// let result = ebm_try_pdep(0b111u8, 0b1010_0000); // None (three bits, two positions)
+/// let result = ebm_try_pdep(0b100u8, 0b1010_0000); // None (bit 2 has no position)
+/// ```
+///
+/// # Function Logic
+/// A value fits a sparse layout when it is representable in as many bits as the mask has set.
+/// This is stricter than comparing set-bit counts: it also rejects a value with few set bits
+/// that sit too high, because `ebm_pdep` would drop those just the same. Negative signed
+/// values only fit a mask with every bit set.
+///
+/// # Safety Considerations
+/// - Never shifts by the full width or more
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_try_pdep<T: EbmInt>(src: T, mask: T) -> Option<T> {
+    // Every set source bit needs a mask position to land on
+    if ebm_and(src, ebmnot(ebm_low_mask::<T>(mask.count_ones()))) != T::ZERO {
+        return None;
+    }
+    Some(ebm_pdep(src, mask))
+}
@@ -1,3 +1,95 @@
+// Bitwise Arithmetic Advanced Addition Operations for Eidolon Math Library
+// This module contains advanced addition operations that extend the basic addition function
+// They report overflow alongside the wrapped result instead of panicking
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
+// Import the integer trait that provides the wrapping and checked intrinsics
+use crate::bits::ebm_int::EbmInt;
 
+/// Adds two values and reports whether the addition overflowed
+///
+/// # Arguments
+/// * `a` - The first addend
+/// * `b` - The second addend
+///
+/// # Returns
+/// * `(T, bool)` - The wrapped sum and `true` if the exact sum does not fit in `T`
+///
+/// # Implementation Details
+/// This function combines the two overflow-aware intrinsics:
+/// 1. Computes the sum modulo `2^width` with `wrapping_add`
+/// 2. Detects overflow with `checked_add`, which fails exactly when the sum does not fit
+/// 3. For unsigned types the flag is the carry out of the top bit
+/// 4. For signed types it is the signed overflow flag
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Compiles to a single add and a flag read
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_add::ebm_overflowing_add;
+/// let result = ebm_overflowing_add(200u8, 100u8); // (44, true)
+/// let result = ebm_overflowing_add(1u8, 2u8); // (3, false)
+/// let result = ebm_overflowing_add(127i8, 1i8); // (-128, true)
+/// ```
+///
+/// # Function Logic
+/// Multi-precision arithmetic needs both the low part of a sum and the carry into the next
+/// limb. Returning them together matches the flags an add instruction produces, and keeps the
+/// basic `ebm_add` free to panic on overflow in debug builds.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe wrapping and checked intrinsics
+/// - No undefined behavior and no overflow panic in any build mode
+pub fn ebm_overflowing_add<T: EbmInt>(a: T, b: T) -> (T, bool) {
+    // The checked sum only fails when the wrapped sum lost a carry
+    (a.wrapping_add(b), a.checked_add(b).is_none())
+}
 
+/// Adds two values and an incoming carry, reporting the outgoing carry
+///
+/// # Arguments
+/// * `a` - The first addend
+/// * `b` - The second addend
+/// * `carry_in` - The carry from the next lower limb, added as one
+///
+/// # Returns
+/// * `(T, bool)` - The wrapped sum and `true` if the exact sum `a + b + carry_in` does not fit in `T`
+///
+/// # Implementation Details
+/// This function chains two overflowing additions:
+/// 1. Adds `a` and `b` with `ebm_overflowing_add`
+/// 2. Adds the carry as `T::ONE` or `T::ZERO` to the partial sum the same way
+/// 3. Reports overflow when exactly one of the two steps overflowed
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Typically compiles to a single add-with-carry instruction
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_add::ebm_carrying_add;
+/// let result = ebm_carrying_add(255u8, 0u8, true); // (0, true)
+/// let result = ebm_carrying_add(200u8, 55u8, false); // (255, false)
+/// let result = ebm_carrying_add(127i8, -1i8, true); // (127, false)
+/// ```
+///
+/// # Function Logic
+/// This matches the unstable standard `carrying_add`. For unsigned types the two steps can
+/// never both overflow, so the flag is the carry out of the top bit. For signed types a
+/// negative overflow can be undone by the carry, in which case neither flag survives, so the
+/// flag is the signed overflow of the whole three-term sum.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe wrapping and checked intrinsics
+/// - No undefined behavior and no overflow panic in any build mode
+pub fn ebm_carrying_add<T: EbmInt>(a: T, b: T, carry_in: bool) -> (T, bool) {
+    let (partial, first) = ebm_overflowing_add(a, b);
+    let (sum, second) = ebm_overflowing_add(partial, if carry_in { T::ONE } else { T::ZERO });
+
+    // Two overflows in opposite directions cancel out
+    (sum, first != second)
+}
@@ -1,3 +1,151 @@
+// Bitwise Arithmetic Advanced Division Operations for Eidolon Math Library
+// This module contains advanced division operations built on top of the basic arithmetic functions
+// They return related division results together or round the quotient other than toward zero
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
+// Import necessary standard library components for low-level operations
+use std::ops::{Div, Rem};
 
+// Import the integer trait that provides the constants for the rounding divisions
+use crate::bits::ebm_int::EbmInt;
 
+// Import the basic operations these advanced functions are built on
+use crate::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic::{ebm_div, ebm_mod};
+
+/// Computes the quotient and the remainder of a division in one call
+///
+/// # Arguments
+/// * `a` - The dividend
+/// * `b` - The divisor
+///
+/// # Returns
+/// * `(T, T)` - The quotient `a / b` and the remainder `a % b`
+///
+/// # Implementation Details
+/// This function pairs the two basic division functions:
+/// 1. Computes the quotient with `ebm_div`
+/// 2. Computes the remainder with `ebm_mod`
+/// 3. The compiler merges both into a single hardware division where the target allows it
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One DIV/IDIV instruction on x86, which yields both results
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_div::ebm_divmod;
+/// let result = ebm_divmod(17u8, 5u8); // (3, 2)
+/// let result = ebm_divmod(255u16, 16u16); // (15, 15)
+/// let result = ebm_divmod(-7i32, 2i32); // (-3, -1) (truncating toward zero)
+/// ```
+///
+/// # Function Logic
+/// Digit extraction, base conversion and time formatting all need the quotient and the
+/// remainder of the same division. The results always satisfy `a == q * b + r`, with the
+/// remainder taking the sign of the dividend like Rust's built-in operators.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in operators
+/// - Division by zero panics
+/// - `T::MIN / -1` of signed types overflows and panics
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_divmod<T>(a: T, b: T) -> (T, T)
+where
+    T: Copy + Div<Output = T> + Rem<Output = T>
+{
+    // Both results come from the same division
+    (ebm_div(a, b), ebm_mod(a, b))
+}
+
+/// Divides two values, rounding the quotient to the nearest integer with halves rounded up
+///
+/// # Arguments
+/// * `a` - The non-negative dividend
+/// * `b` - The positive divisor
+///
+/// # Returns
+/// * `T` - `a / b` rounded half up, the value of `(a + b / 2) / b`
+///
+/// # Implementation Details
+/// This function rounds using the remainder instead of adding `b / 2` first:
+/// 1. Checks that the divisor is non-zero
+/// 2. Computes the truncated quotient and the remainder with `ebm_divmod`
+/// 3. Rounds up when the remainder is at least the distance `b - remainder` to the next multiple
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One division, one subtraction and one comparison
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_div::ebm_div_round;
+/// let result = ebm_div_round(7u32, 2u32); // 4 (3.5 rounds up)
+/// let result = ebm_div_round(10u32, 4u32); // 3 (2.5 rounds up)
+/// let result = ebm_div_round(9u32, 4u32); // 2 (2.25 rounds down)
+/// ```
+///
+/// # Function Logic
+/// Converting units by truncating always errs low, which biases sums of many conversions.
+/// Comparing the remainder with its distance to the next multiple decides the rounding exactly
+/// and, unlike `(a + b / 2) / b`, cannot overflow for dividends near `T::MAX`.
+///
+/// # Safety Considerations
+/// - Panics when `b` is zero
+/// - `a` must not be negative and `b` must be positive (checked with a debug assertion)
+/// - No overflow for any valid inputs
+pub fn ebm_div_round<T: EbmInt>(a: T, b: T) -> T {
+    assert!(b != T::ZERO, "divisor must be non-zero");
+    debug_assert!(a >= T::ZERO && b > T::ZERO, "rounding division needs a non-negative dividend and a positive divisor");
+
+    // Round up when the remainder is at least half of the divisor
+    let (quotient, remainder) = ebm_divmod(a, b);
+    if remainder >= b - remainder { quotient + T::ONE } else { quotient }
+}
+
+/// Divides two values, rounding the quotient up
+///
+/// # Arguments
+/// * `a` - The non-negative dividend
+/// * `b` - The positive divisor
+///
+/// # Returns
+/// * `T` - `ceil(a / b)`, the value of `(a + b - 1) / b`
+///
+/// # Implementation Details
+/// This function rounds using the remainder instead of adding `b - 1` first:
+/// 1. Checks that the divisor is non-zero
+/// 2. Computes the truncated quotient and the remainder with `ebm_divmod`
+/// 3. Adds one to the quotient when the remainder is non-zero
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One division and one comparison
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_div::ebm_div_ceil;
+/// let result = ebm_div_ceil(7u32, 2u32); // 4
+/// let result = ebm_div_ceil(8u32, 2u32); // 4 (exact)
+/// let result = ebm_div_ceil(0u32, 2u32); // 0
+/// ```
+///
+/// # Function Logic
+/// Ceiling division counts how many blocks of size `b` are needed to hold `a` items. The
+/// textbook form `(a + b - 1) / b` overflows for dividends near `T::MAX`; checking the
+/// remainder gives the same result for every input without that intermediate sum.
+///
+/// # Safety Considerations
+/// - Panics when `b` is zero
+/// - `a` must not be negative and `b` must be positive (checked with a debug assertion)
+/// - No overflow for any valid inputs
+pub fn ebm_div_ceil<T: EbmInt>(a: T, b: T) -> T {
+    assert!(b != T::ZERO, "divisor must be non-zero");
+    debug_assert!(a >= T::ZERO && b > T::ZERO, "ceiling division needs a non-negative dividend and a positive divisor");
+
+    // Any remainder needs one more block
+    let (quotient, remainder) = ebm_divmod(a, b);
+    if remainder != T::ZERO { quotient + T::ONE } else { quotient }
+}
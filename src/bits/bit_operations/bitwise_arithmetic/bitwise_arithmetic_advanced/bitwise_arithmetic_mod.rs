@@ -1,3 +1,61 @@
+// Bitwise Arithmetic Advanced Modulo Operations for Eidolon Math Library
+// This module contains advanced modulo operations built on top of the basic arithmetic functions
+// They replace division-based remainders with masks wherever the modulus allows it
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
+// Import the integer trait that provides the constants
+use crate::bits::ebm_int::EbmInt;
 
+// Import the basic operations these advanced functions are built on
+use crate::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic::ebm_sub;
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::ebm_and;
 
+/// Computes `a % modulus_pow2` with a mask when the modulus is a power of two
+///
+/// # Arguments
+/// * `a` - The dividend for the modulo operation
+/// * `modulus_pow2` - The modulus, which must be a power of two
+///
+/// # Returns
+/// * `T` - The remainder of `a` divided by `modulus_pow2`
+///
+/// # Implementation Details
+/// This function replaces the division with a single AND:
+/// 1. Builds the mask `modulus_pow2 - 1` with `ebm_sub`, which has every bit below the modulus set
+/// 2. Keeps only those low bits of `a` with `ebm_and`
+/// 3. Checks the power-of-two precondition with a debug assertion
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One subtraction and one AND instead of a DIV instruction
+/// - Branchless in release builds
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_mod::ebm_mod_pow2;
+/// let result = ebm_mod_pow2(13u32, 8u32); // 5 (13 % 8)
+/// let result = ebm_mod_pow2(255u8, 16u8); // 15
+/// let result = ebm_mod_pow2(1024u64, 1024u64); // 0
+/// ```
+///
+/// # Function Logic
+/// For a power of two `2^k`, the remainder of a division is exactly the low `k` bits of the
+/// dividend. This is the usual fast path for indexing into power-of-two-sized ring buffers.
+/// For negative signed dividends the mask yields the non-negative (Euclidean) remainder,
+/// which differs from the sign-following result of the `%` operator.
+///
+/// # Safety Considerations
+/// - `modulus_pow2` must be a positive power of two (checked with a debug assertion)
+/// - No undefined behavior possible with valid numeric types
+/// - Compiler ensures type safety at compile time
+pub fn ebm_mod_pow2<T: EbmInt>(a: T, modulus_pow2: T) -> T {
+    // A positive power of two has exactly one set bit and is not the sign bit
+    debug_assert!(
+        modulus_pow2 > T::ZERO && modulus_pow2.count_ones() == 1,
+        "modulus must be a power of two"
+    );
+
+    // The low bits below the modulus are the remainder
+    ebm_and(a, ebm_sub(modulus_pow2, T::ONE))
+}
@@ -1,3 +1,142 @@
+// Bitwise Arithmetic Advanced Subtraction Operations for Eidolon Math Library
+// This module contains advanced subtraction operations that extend the basic subtraction function
+// They report overflow alongside the wrapped result instead of panicking
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
+// Import the integer trait that provides the wrapping and checked intrinsics
+use crate::bits::ebm_int::EbmInt;
 
+/// Subtracts two values and reports whether the subtraction overflowed
+///
+/// # Arguments
+/// * `a` - The minuend
+/// * `b` - The subtrahend
+///
+/// # Returns
+/// * `(T, bool)` - The wrapped difference and `true` if the exact difference does not fit in `T`
+///
+/// # Implementation Details
+/// This function combines the two overflow-aware intrinsics:
+/// 1. Computes the difference modulo `2^width` with `wrapping_sub`
+/// 2. Detects overflow with `checked_sub`, which fails exactly when the difference does not fit
+/// 3. For unsigned types the flag is the borrow into the top bit
+/// 4. For signed types it is the signed overflow flag
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Compiles to a single subtract and a flag read
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_sub::ebm_overflowing_sub;
+/// let result = ebm_overflowing_sub(0u8, 1u8); // (255, true)
+/// let result = ebm_overflowing_sub(5u8, 3u8); // (2, false)
+/// let result = ebm_overflowing_sub(-128i8, 1i8); // (127, true)
+/// ```
+///
+/// # Function Logic
+/// This is the subtraction counterpart of `ebm_overflowing_add`. Multi-precision subtraction
+/// uses the flag as the borrow taken from the next limb.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe wrapping and checked intrinsics
+/// - No undefined behavior and no overflow panic in any build mode
+pub fn ebm_overflowing_sub<T: EbmInt>(a: T, b: T) -> (T, bool) {
+    // The checked difference only fails when the wrapped difference needed a borrow
+    (a.wrapping_sub(b), a.checked_sub(b).is_none())
+}
 
+/// Subtracts two values, clamping the result at zero instead of underflowing (monus)
+///
+/// # Arguments
+/// * `a` - The minuend
+/// * `b` - The subtrahend
+///
+/// # Returns
+/// * `T` - `a - b` when `a > b`, otherwise zero
+///
+/// # Implementation Details
+/// This function implements truncated subtraction:
+/// 1. Returns zero whenever `a <= b`, so the result can never drop below zero
+/// 2. Otherwise computes the difference with `checked_sub`
+/// 3. A positive signed difference too large for `T` saturates at `T::MAX`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One comparison and one subtraction, typically compiled to a branchless select
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_sub::ebm_monus;
+/// let result = ebm_monus(10u8, 3u8); // 7
+/// let result = ebm_monus(3u8, 10u8); // 0
+/// let result = ebm_monus(5u32, 5u32); // 0
+/// ```
+///
+/// # Function Logic
+/// Truncated subtraction is the natural difference on the natural numbers: remaining capacity,
+/// time left until a deadline or bytes still to send can never be negative. For unsigned types
+/// it equals `saturating_sub`. For signed types the result is still never negative, unlike
+/// `saturating_sub`, which clamps at `T::MIN`.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe checked intrinsics
+/// - No undefined behavior and no overflow panic in any build mode
+pub fn ebm_monus<T: EbmInt>(a: T, b: T) -> T {
+    // Anything that would go below zero stops at zero
+    if a <= b {
+        return T::ZERO;
+    }
+
+    // The difference is positive here, so only the upper bound can be exceeded
+    a.checked_sub(b).unwrap_or(T::MAX)
+}
+
+/// Subtracts a value and an incoming borrow, reporting the outgoing borrow
+///
+/// # Arguments
+/// * `a` - The minuend
+/// * `b` - The subtrahend
+/// * `borrow_in` - The borrow taken by the next lower limb, subtracted as one
+///
+/// # Returns
+/// * `(T, bool)` - The wrapped difference and `true` if the exact difference `a - b - borrow_in`
+///   does not fit in `T`
+///
+/// # Implementation Details
+/// This function chains two overflowing subtractions:
+/// 1. Subtracts `b` from `a` with `ebm_overflowing_sub`
+/// 2. Subtracts the borrow as `T::ONE` or `T::ZERO` from the partial difference the same way
+/// 3. Reports overflow when exactly one of the two steps overflowed
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Typically compiles to a single subtract-with-borrow instruction
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_sub::ebm_borrowing_sub;
+/// let result = ebm_borrowing_sub(0u8, 0u8, true); // (255, true)
+/// let result = ebm_borrowing_sub(5u8, 3u8, true); // (1, false)
+/// let result = ebm_borrowing_sub(-128i8, 1i8, false); // (127, true)
+/// ```
+///
+/// # Function Logic
+/// This matches the unstable standard `borrowing_sub` and is the counterpart of
+/// `ebm_carrying_add`. For unsigned types the two steps can never both borrow; for signed
+/// types overflows in opposite directions cancel, so the flag is the signed overflow of the
+/// whole three-term difference.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe wrapping and checked intrinsics
+/// - No undefined behavior and no overflow panic in any build mode
+pub fn ebm_borrowing_sub<T: EbmInt>(a: T, b: T, borrow_in: bool) -> (T, bool) {
+    let (partial, first) = ebm_overflowing_sub(a, b);
+    let (difference, second) = ebm_overflowing_sub(partial, if borrow_in { T::ONE } else { T::ZERO });
+
+    // Two overflows in opposite directions cancel out
+    (difference, first != second)
+}
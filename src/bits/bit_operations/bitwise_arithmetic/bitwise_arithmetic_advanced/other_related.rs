@@ -1,3 +1,1163 @@
+// Bitwise Arithmetic Other Related Operations for Eidolon Math Library
+// This module contains arithmetic helpers that are not built on the basic arithmetic functions
+// They rely on the wrapping, checked and counting intrinsics exposed through the EbmInt trait
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
+// Import necessary standard library components for the limb comparison
+use std::cmp::Ordering;
 
+// Import the integer traits that provide the constants, wrapping intrinsics and double-width partners
+use crate::bits::ebm_int::{EbmInt, EbmNarrow, EbmWiden};
 
+// Import the carry-reporting addition and subtraction used by the limb arithmetic
+use crate::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_add::ebm_overflowing_add;
+use crate::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_sub::ebm_overflowing_sub;
+
+// Import the funnel shift used to move bits between limbs
+use crate::bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_shift::ebm_funnel_shl;
+
+// Import the rotation and XOR used by the hash mixer
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::ebmxor;
+use crate::bits::bit_operations::bitwise_shifting::bitwise_shifting::ebm_left_rotate;
+
+/// Increments a value by one, wrapping around at the type boundary
+///
+/// # Arguments
+/// * `a` - The value to increment
+///
+/// # Returns
+/// * `T` - `a + 1`, or `T::MIN` when `a` is `T::MAX`
+///
+/// # Implementation Details
+/// This function uses the wrapping addition intrinsic of the type:
+/// 1. Adds one with `wrapping_add`
+/// 2. Wraps from `T::MAX` to `T::MIN` instead of panicking in debug builds
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Compiles to a single INC or ADD instruction
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_wrapping_inc;
+/// let result = ebm_wrapping_inc(5u8); // 6
+/// let result = ebm_wrapping_inc(u8::MAX); // 0
+/// let result = ebm_wrapping_inc(i8::MAX); // -128
+/// ```
+///
+/// # Function Logic
+/// This is a named step for cyclic counters such as ring-buffer indices and sequence numbers,
+/// where reaching the top of the range must continue from the bottom.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe wrapping intrinsics
+/// - No undefined behavior and no overflow panic in any build mode
+/// - Compiler ensures type safety at compile time
+pub fn ebm_wrapping_inc<T: EbmInt>(a: T) -> T {
+    // Step forward by one, continuing from T::MIN after T::MAX
+    a.wrapping_add(T::ONE)
+}
+
+/// Decrements a value by one, wrapping around at the type boundary
+///
+/// # Arguments
+/// * `a` - The value to decrement
+///
+/// # Returns
+/// * `T` - `a - 1`, or `T::MAX` when `a` is `T::MIN`
+///
+/// # Implementation Details
+/// This function uses the wrapping subtraction intrinsic of the type:
+/// 1. Subtracts one with `wrapping_sub`
+/// 2. Wraps from `T::MIN` to `T::MAX` instead of panicking in debug builds
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Compiles to a single DEC or SUB instruction
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_wrapping_dec;
+/// let result = ebm_wrapping_dec(5u8); // 4
+/// let result = ebm_wrapping_dec(0u8); // 255
+/// let result = ebm_wrapping_dec(i8::MIN); // 127
+/// ```
+///
+/// # Function Logic
+/// This is the backward step of `ebm_wrapping_inc`, used for cyclic counters that count down.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe wrapping intrinsics
+/// - No undefined behavior and no overflow panic in any build mode
+/// - Compiler ensures type safety at compile time
+pub fn ebm_wrapping_dec<T: EbmInt>(a: T) -> T {
+    // Step backward by one, continuing from T::MAX after T::MIN
+    a.wrapping_sub(T::ONE)
+}
+
+/// Computes the two's-complement negation of a value
+///
+/// # Arguments
+/// * `a` - The value to negate
+///
+/// # Returns
+/// * `T` - `!a + 1`, wrapping around at the type boundary
+///
+/// # Implementation Details
+/// This function spells out the two's-complement definition of negation:
+/// 1. Inverts every bit of the operand
+/// 2. Adds one with `wrapping_add`, so `T::MIN` of a signed type maps to itself
+/// 3. Works identically for signed and unsigned types
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Compiles to a single NEG instruction
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_negate;
+/// let result = ebm_negate(1u8); // 255
+/// let result = ebm_negate(-5i8); // 5
+/// let result = ebm_negate(0u32); // 0
+/// ```
+///
+/// # Function Logic
+/// For signed types this is ordinary negation, except that `T::MIN` has no positive counterpart
+/// and wraps to itself. For unsigned types the result is the additive inverse modulo
+/// `2^width`: `a + ebm_negate(a)` is always zero, which is useful in modular arithmetic.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe wrapping intrinsics
+/// - No undefined behavior and no overflow panic in any build mode
+/// - Compiler ensures type safety at compile time
+pub fn ebm_negate<T: EbmInt>(a: T) -> T {
+    // Two's-complement negation: invert every bit, then add one
+    (!a).wrapping_add(T::ONE)
+}
+
+/// Negates a value, returning `None` when the negation overflows
+///
+/// # Arguments
+/// * `a` - The value to negate
+///
+/// # Returns
+/// * `Option<T>` - `Some(-a)`, or `None` when `-a` is not representable in `T`
+///
+/// # Implementation Details
+/// This function forwards to the checked intrinsic of the type:
+/// 1. Calls `checked_neg` through the `EbmInt` trait
+/// 2. Signed types fail only for `T::MIN`, whose magnitude exceeds `T::MAX`
+/// 3. Unsigned types succeed only for zero, the one value with a non-negative negation
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One negation and one overflow-flag check
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_checked_neg;
+/// let result = ebm_checked_neg(5i8); // Some(-5)
+/// let result = ebm_checked_neg(i8::MIN); // None
+/// let result = ebm_checked_neg(1u8); // None
+/// ```
+///
+/// # Function Logic
+/// `ebm_negate` silently maps `T::MIN` to itself, so negating an offset that happens to be the
+/// minimum value keeps the wrong sign. This variant reports that case instead, letting callers
+/// reject or saturate it explicitly.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe checked intrinsics
+/// - No undefined behavior and no overflow panic in any build mode
+/// - Compiler ensures type safety at compile time
+pub fn ebm_checked_neg<T: EbmInt>(a: T) -> Option<T> {
+    // Let the type report whether the negation fits
+    a.checked_neg()
+}
+
+// Turns a condition into an all-ones mask when true and zero when false, without branching
+fn condition_mask<T: EbmInt>(condition: bool) -> T {
+    T::ZERO.wrapping_sub(T::from_u32(condition as u32))
+}
+
+/// Returns the smaller of two values without branching
+///
+/// # Arguments
+/// * `a` - The first value to compare
+/// * `b` - The second value to compare
+///
+/// # Returns
+/// * `T` - The smaller of `a` and `b`
+///
+/// # Implementation Details
+/// This function selects the result with a mask instead of a jump:
+/// 1. Turns the comparison `a < b` into a mask of all ones or all zeros
+/// 2. Computes `b ^ ((a ^ b) & mask)`, which yields `a` for an all-ones mask and `b` otherwise
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Branchless, so the timing does not depend on the operands
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_min;
+/// let result = ebm_min(3u8, 7u8); // 3
+/// let result = ebm_min(-4i32, 2i32); // -4
+/// ```
+///
+/// # Function Logic
+/// XOR-ing `b` with `a ^ b` produces `a`, while XOR-ing with zero leaves `b`. The mask decides
+/// which of the two happens, so the minimum is selected with plain arithmetic.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in operators and wrapping intrinsics
+/// - No undefined behavior possible with valid numeric types
+/// - Compiler ensures type safety at compile time
+pub fn ebm_min<T: EbmInt>(a: T, b: T) -> T {
+    // Select a when it is smaller, b otherwise
+    b ^ ((a ^ b) & condition_mask::<T>(a < b))
+}
+
+/// Returns the larger of two values without branching
+///
+/// # Arguments
+/// * `a` - The first value to compare
+/// * `b` - The second value to compare
+///
+/// # Returns
+/// * `T` - The larger of `a` and `b`
+///
+/// # Implementation Details
+/// This function selects the result with a mask instead of a jump:
+/// 1. Turns the comparison `a > b` into a mask of all ones or all zeros
+/// 2. Computes `b ^ ((a ^ b) & mask)`, which yields `a` for an all-ones mask and `b` otherwise
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Branchless, so the timing does not depend on the operands
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_max;
+/// let result = ebm_max(3u8, 7u8); // 7
+/// let result = ebm_max(-4i32, 2i32); // 2
+/// ```
+///
+/// # Function Logic
+/// This is the mirror image of `ebm_min`, selecting `a` whenever it is the larger operand.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in operators and wrapping intrinsics
+/// - No undefined behavior possible with valid numeric types
+/// - Compiler ensures type safety at compile time
+pub fn ebm_max<T: EbmInt>(a: T, b: T) -> T {
+    // Select a when it is larger, b otherwise
+    b ^ ((a ^ b) & condition_mask::<T>(a > b))
+}
+
+/// Restricts a value to the inclusive range `[lo, hi]` without branching
+///
+/// # Arguments
+/// * `value` - The value to restrict
+/// * `lo` - The lower bound of the range
+/// * `hi` - The upper bound of the range
+///
+/// # Returns
+/// * `T` - `lo` if `value < lo`, `hi` if `value > hi`, and `value` otherwise
+///
+/// # Implementation Details
+/// This function composes the branchless selections:
+/// 1. Raises the value to at least `lo` with `ebm_max`
+/// 2. Lowers the result to at most `hi` with `ebm_min`
+/// 3. Checks that the range is not empty with a debug assertion
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Branchless, so the timing does not depend on the operands
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_clamp;
+/// let result = ebm_clamp(300u16, 0u16, 255u16); // 255
+/// let result = ebm_clamp(-7i8, -5i8, 5i8); // -5
+/// let result = ebm_clamp(3i8, -5i8, 5i8); // 3
+/// ```
+///
+/// # Function Logic
+/// Clamping keeps signal values inside a valid range. Building it from the branchless minimum and
+/// maximum keeps the whole operation constant-time, which suits DSP pipelines and secret data.
+///
+/// # Safety Considerations
+/// - `lo` must not be greater than `hi` (checked with a debug assertion)
+/// - No undefined behavior possible with valid numeric types
+/// - Compiler ensures type safety at compile time
+pub fn ebm_clamp<T: EbmInt>(value: T, lo: T, hi: T) -> T {
+    // An empty range has no valid result
+    debug_assert!(lo <= hi, "clamp range must satisfy lo <= hi");
+
+    // Raise to the lower bound, then cap at the upper bound
+    ebm_min(ebm_max(value, lo), hi)
+}
+
+/// Raises a value to an integer power using exponentiation by squaring, wrapping on overflow
+///
+/// # Arguments
+/// * `base` - The value to raise to a power
+/// * `exp` - The exponent
+///
+/// # Returns
+/// * `T` - `base^exp` modulo `2^width`
+///
+/// # Implementation Details
+/// This function processes the exponent one bit at a time:
+/// 1. Starts with an accumulator of one
+/// 2. Multiplies the accumulator by the current base whenever the low exponent bit is set
+/// 3. Squares the base and shifts the exponent right for the next bit
+/// 4. Uses `wrapping_mul` throughout, so overflow wraps instead of panicking
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - At most `2 * log2(exp)` multiplications instead of `exp`
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_pow;
+/// let result = ebm_pow(3u32, 4); // 81
+/// let result = ebm_pow(2u8, 8); // 0 (256 wraps to 0)
+/// let result = ebm_pow(-2i32, 3); // -8
+/// ```
+///
+/// # Function Logic
+/// Writing the exponent in binary, `base^exp` is the product of `base^(2^i)` over every set
+/// bit `i`. Squaring produces those factors in sequence, which keeps polynomial evaluation fast
+/// even for large exponents. Any exponent of zero yields one, including `0^0`.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe wrapping intrinsics
+/// - No undefined behavior and no overflow panic in any build mode
+/// - Use `ebm_checked_pow` when overflow must be detected
+pub fn ebm_pow<T: EbmInt>(base: T, exp: u32) -> T {
+    let mut result = T::ONE;
+    let mut factor = base;
+    let mut remaining = exp;
+
+    // Multiply in base^(2^i) for every set bit i of the exponent
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result = result.wrapping_mul(factor);
+        }
+        remaining >>= 1;
+        factor = factor.wrapping_mul(factor);
+    }
+
+    result
+}
+
+/// Raises a value to an integer power using exponentiation by squaring, detecting overflow
+///
+/// # Arguments
+/// * `base` - The value to raise to a power
+/// * `exp` - The exponent
+///
+/// # Returns
+/// * `Option<T>` - `Some(base^exp)`, or `None` if the result does not fit in `T`
+///
+/// # Implementation Details
+/// This function follows `ebm_pow` with checked multiplications:
+/// 1. Multiplies the accumulator with `checked_mul` whenever the low exponent bit is set
+/// 2. Squares the base with `checked_mul` only while exponent bits remain
+/// 3. Returns `None` as soon as any multiplication overflows
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - At most `2 * log2(exp)` multiplications instead of `exp`
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_checked_pow;
+/// let result = ebm_checked_pow(3u32, 4); // Some(81)
+/// let result = ebm_checked_pow(2u8, 8); // None (256 does not fit in u8)
+/// let result = ebm_checked_pow(-2i8, 7); // Some(-128)
+/// ```
+///
+/// # Function Logic
+/// The base is only squared when another exponent bit follows, so an unused final square can
+/// never report a false overflow. Overflow of the real result is always detected.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe checked intrinsics
+/// - No undefined behavior and no overflow panic in any build mode
+pub fn ebm_checked_pow<T: EbmInt>(base: T, exp: u32) -> Option<T> {
+    let mut result = T::ONE;
+    let mut factor = base;
+    let mut remaining = exp;
+
+    // Multiply in base^(2^i) for every set bit i of the exponent
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result = result.checked_mul(factor)?;
+        }
+        remaining >>= 1;
+
+        // Only square when the square is still needed
+        if remaining > 0 {
+            factor = factor.checked_mul(factor)?;
+        }
+    }
+
+    Some(result)
+}
+
+/// Computes the floor of the square root of a non-negative integer
+///
+/// # Arguments
+/// * `a` - The non-negative value to take the square root of
+///
+/// # Returns
+/// * `T` - The largest integer whose square is less than or equal to `a`
+///
+/// # Implementation Details
+/// This function uses the binary digit-by-digit method:
+/// 1. Starts with the highest power of four that fits in the type
+/// 2. Lowers it until it no longer exceeds `a`
+/// 3. For each power of four, tries to add the matching result bit and subtracts its
+///    contribution from the remainder when it fits
+/// 4. Halves the partial result and moves to the next lower power of four every step
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - At most `width / 2` iterations of shifts, additions and comparisons, no multiplications
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_isqrt;
+/// let result = ebm_isqrt(17u32); // 4
+/// let result = ebm_isqrt(16u32); // 4
+/// let result = ebm_isqrt(255u8); // 15
+/// ```
+///
+/// # Function Logic
+/// Each iteration decides one bit of the root, from the most significant down, exactly like
+/// long-hand square roots in base two. The partial values never exceed the input, so the
+/// method is free of overflow for every width.
+///
+/// # Safety Considerations
+/// - `a` must not be negative (checked with a debug assertion)
+/// - No overflow possible in any build mode
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_isqrt<T: EbmInt>(a: T) -> T {
+    // Negative values have no real square root
+    debug_assert!(a >= T::ZERO, "square root of a negative value");
+
+    // Start from the highest power of four that fits in the type and does not exceed a
+    let mut bit = T::ONE << (T::BITS - 2);
+    while bit > a {
+        bit = bit >> 2;
+    }
+
+    let mut remainder = a;
+    let mut result = T::ZERO;
+
+    // Decide one bit of the root per power of four
+    while bit != T::ZERO {
+        if remainder >= result + bit {
+            remainder = remainder - (result + bit);
+            result = (result >> 1) + bit;
+        } else {
+            result = result >> 1;
+        }
+        bit = bit >> 2;
+    }
+
+    result
+}
+
+/// Computes the ceiling of the square root of a non-negative integer
+///
+/// # Arguments
+/// * `a` - The non-negative value to take the square root of
+///
+/// # Returns
+/// * `T` - The smallest integer whose square is greater than or equal to `a`
+///
+/// # Implementation Details
+/// This function corrects the floor square root:
+/// 1. Computes the floor root with `ebm_isqrt`
+/// 2. Returns it unchanged when its square equals `a`
+/// 3. Otherwise returns the floor root plus one
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One floor square root, one multiplication and one comparison
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_isqrt_ceil;
+/// let result = ebm_isqrt_ceil(17u32); // 5
+/// let result = ebm_isqrt_ceil(16u32); // 4
+/// let result = ebm_isqrt_ceil(0u32); // 0
+/// ```
+///
+/// # Function Logic
+/// When sizing a square grid that has to cover an area, the side length must be rounded up.
+/// The floor root squared never exceeds `a`, so the correction can neither overflow nor
+/// overshoot.
+///
+/// # Safety Considerations
+/// - `a` must not be negative (checked with a debug assertion)
+/// - No overflow possible in any build mode
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_isqrt_ceil<T: EbmInt>(a: T) -> T {
+    let root = ebm_isqrt(a);
+
+    // Round up unless a is a perfect square
+    if root * root == a {
+        root
+    } else {
+        root + T::ONE
+    }
+}
+
+// The first 128 bits of the fractional part of the golden ratio, 2^128 / phi, as 32-bit words
+const GOLDEN_RATIO_WORDS: [u32; 4] = [0x9E37_79B9, 0x7F4A_7C15, 0xF39C_C060, 0x5CED_C834];
+
+// Returns the top `T::BITS` bits of the golden ratio fraction, forced odd
+fn golden_multiplier<T: EbmInt>() -> T {
+    // Narrow types take the top bits of the first word
+    if T::BITS < 32 {
+        return T::from_u32(GOLDEN_RATIO_WORDS[0] >> (32 - T::BITS)) | T::ONE;
+    }
+
+    // Wider types concatenate as many words as they hold
+    let mut multiplier = T::from_u32(GOLDEN_RATIO_WORDS[0]);
+    for &word in &GOLDEN_RATIO_WORDS[1..(T::BITS / 32) as usize] {
+        multiplier = (multiplier << 32) | T::from_u32(word);
+    }
+    multiplier | T::ONE
+}
+
+/// Mixes two values into one with a single rotate-XOR-multiply hash round
+///
+/// # Arguments
+/// * `a` - The running hash state
+/// * `b` - The value to mix into the state
+///
+/// # Returns
+/// * `T` - The new hash state
+///
+/// # Implementation Details
+/// This function performs one round in the style of the SplitMix and xxHash mixers:
+/// 1. Rotates `b` left by `width / 2 - 1` with `ebm_left_rotate` and XORs it into `a`
+/// 2. Multiplies by the golden ratio constant, wrapping on overflow
+/// 3. XORs in the value rotated by half the width so the high product bits reach the low bits
+/// 4. Multiplies by the golden ratio constant once more
+///
+/// The multiplier is `floor(2^width / phi)` forced odd: `0x9F` for 8 bits, `0x9E37` for 16,
+/// `0x9E3779B9` for 32, `0x9E3779B97F4A7C15` for 64 and
+/// `0x9E3779B97F4A7C15F39CC0605CEDC834` for 128.
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Two multiplications, two rotations and two XORs
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_mix;
+/// let result = ebm_mix(0u64, 1u64); // A well-scrambled 64-bit value
+/// let result = ebm_mix(ebm_mix(0u32, 1u32), 2u32); // Chaining builds a hash over several values
+/// ```
+///
+/// # Function Logic
+/// Multiplication by an odd constant is invertible and spreads every input bit into all higher
+/// bits, but never into lower ones. Rotating by half the width between the two multiplications
+/// feeds the well-mixed high bits back into the low bits, so a single flipped input bit flips
+/// about half of the output bits. The golden ratio constant has no regular bit pattern, which
+/// avoids systematic cancellations. This is a building block for custom hashers, not a
+/// cryptographic hash.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe wrapping intrinsics
+/// - No undefined behavior and no overflow panic in any build mode
+/// - Not suitable where an attacker may choose inputs to force collisions
+pub fn ebm_mix<T: EbmInt>(a: T, b: T) -> T {
+    let multiplier = golden_multiplier::<T>();
+
+    // Combine the inputs, offsetting b so that a == b does not cancel out
+    let mut x = ebmxor(a, ebm_left_rotate(b, T::BITS / 2 - 1));
+
+    // Spread upward, fold the high half down, and spread again
+    x = x.wrapping_mul(multiplier);
+    x = ebmxor(x, ebm_left_rotate(x, T::BITS / 2));
+    x.wrapping_mul(multiplier)
+}
+
+/// Adds one multi-precision number into another, limb by limb
+///
+/// # Arguments
+/// * `a` - The first addend as little-endian 64-bit limbs, overwritten with the sum
+/// * `b` - The second addend as little-endian 64-bit limbs
+///
+/// # Returns
+/// * `bool` - The carry out of the most significant limb
+///
+/// # Implementation Details
+/// This function performs schoolbook addition with carry propagation:
+/// 1. Checks that both numbers have the same number of limbs
+/// 2. Adds each limb pair with `ebm_overflowing_add`
+/// 3. Adds the incoming carry to that sum with a second `ebm_overflowing_add`
+/// 4. Carries into the next limb if either addition overflowed (both never can)
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Linear time in the number of limbs
+/// - Cache-friendly sequential access of both buffers
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_add_limbs;
+/// let mut a = [u64::MAX, 0];
+/// let carry = ebm_add_limbs(&mut a, &[1, 0]); // a == [0, 1], carry == false
+/// let mut b = [u64::MAX, u64::MAX];
+/// let carry = ebm_add_limbs(&mut b, &[1, 0]); // b == [0, 0], carry == true
+/// ```
+///
+/// # Function Logic
+/// Limb 0 is the least significant, so carries travel from the start of the slice to the end.
+/// The returned carry is the bit that would start a new limb, which lets callers grow the
+/// number or detect overflow of a fixed-size value.
+///
+/// # Safety Considerations
+/// - Panics when the slices have different lengths
+/// - No overflow panic in any build mode
+/// - No undefined behavior possible
+pub fn ebm_add_limbs(a: &mut [u64], b: &[u64]) -> bool {
+    // Both numbers must have the same number of limbs
+    assert_eq!(a.len(), b.len(), "limb slices must have equal length");
+
+    let mut carry = false;
+    for (limb, &addend) in a.iter_mut().zip(b) {
+        // Add the limbs, then the carry from the limb below
+        let (partial, carry_limbs) = ebm_overflowing_add(*limb, addend);
+        let (sum, carry_in) = ebm_overflowing_add(partial, carry as u64);
+        *limb = sum;
+        carry = carry_limbs | carry_in;
+    }
+
+    carry
+}
+
+/// Subtracts one multi-precision number from another, limb by limb
+///
+/// # Arguments
+/// * `a` - The minuend as little-endian 64-bit limbs, overwritten with the difference
+/// * `b` - The subtrahend as little-endian 64-bit limbs
+///
+/// # Returns
+/// * `bool` - The borrow out of the most significant limb, `true` when `b > a`
+///
+/// # Implementation Details
+/// This function performs schoolbook subtraction with borrow propagation:
+/// 1. Checks that both numbers have the same number of limbs
+/// 2. Subtracts each limb pair with `ebm_overflowing_sub`
+/// 3. Subtracts the incoming borrow from that difference with a second `ebm_overflowing_sub`
+/// 4. Borrows from the next limb if either subtraction overflowed (both never can)
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Linear time in the number of limbs
+/// - Cache-friendly sequential access of both buffers
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_sub_limbs;
+/// let mut a = [0, 1];
+/// let borrow = ebm_sub_limbs(&mut a, &[1, 0]); // a == [u64::MAX, 0], borrow == false
+/// let mut b = [0, 0];
+/// let borrow = ebm_sub_limbs(&mut b, &[1, 0]); // b == [u64::MAX, u64::MAX], borrow == true
+/// ```
+///
+/// # Function Logic
+/// This is the subtraction counterpart of `ebm_add_limbs`. A final borrow means the result
+/// wrapped around, so the difference is negative and `a` holds its two's complement.
+///
+/// # Safety Considerations
+/// - Panics when the slices have different lengths
+/// - No overflow panic in any build mode
+/// - No undefined behavior possible
+pub fn ebm_sub_limbs(a: &mut [u64], b: &[u64]) -> bool {
+    // Both numbers must have the same number of limbs
+    assert_eq!(a.len(), b.len(), "limb slices must have equal length");
+
+    let mut borrow = false;
+    for (limb, &subtrahend) in a.iter_mut().zip(b) {
+        // Subtract the limbs, then the borrow taken by the limb below
+        let (partial, borrow_limbs) = ebm_overflowing_sub(*limb, subtrahend);
+        let (difference, borrow_in) = ebm_overflowing_sub(partial, borrow as u64);
+        *limb = difference;
+        borrow = borrow_limbs | borrow_in;
+    }
+
+    borrow
+}
+
+/// Compares two multi-precision numbers of the same length
+///
+/// # Arguments
+/// * `a` - The first number as little-endian 64-bit limbs
+/// * `b` - The second number as little-endian 64-bit limbs
+///
+/// # Returns
+/// * `Ordering` - How `a` compares to `b` as unsigned integers
+///
+/// # Implementation Details
+/// This function compares from the most significant limb down:
+/// 1. Checks that both numbers have the same number of limbs
+/// 2. Walks the limb pairs from the end of the slices to the start
+/// 3. Returns the ordering of the first pair that differs
+/// 4. Returns `Ordering::Equal` when every limb matches
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Stops at the first differing limb
+/// - Linear time in the number of limbs in the worst case
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_cmp_limbs;
+/// use std::cmp::Ordering;
+/// let result = ebm_cmp_limbs(&[5, 1], &[7, 0]); // Ordering::Greater (the high limb decides)
+/// let result = ebm_cmp_limbs(&[5, 1], &[7, 1]); // Ordering::Less (the low limb decides)
+/// let result = ebm_cmp_limbs(&[5, 1], &[5, 1]); // Ordering::Equal
+/// ```
+///
+/// # Function Logic
+/// Slices compare lexicographically from the first element, which is the least significant limb
+/// here, so the built-in ordering would be wrong. Comparing the reversed limbs gives the
+/// numeric ordering, which is what sorting arbitrary-precision values needs.
+///
+/// # Safety Considerations
+/// - Panics when the slices have different lengths
+/// - No undefined behavior possible
+pub fn ebm_cmp_limbs(a: &[u64], b: &[u64]) -> Ordering {
+    // Both numbers must have the same number of limbs
+    assert_eq!(a.len(), b.len(), "limb slices must have equal length");
+
+    // The most significant differing limb decides the ordering
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+/// Shifts a multi-precision number left by an arbitrary number of bits
+///
+/// # Arguments
+/// * `limbs` - The number as little-endian 64-bit limbs, shifted in place
+/// * `amount` - The number of bit positions to shift left
+///
+/// # Returns
+/// * `()` - The number is shifted in place; bits shifted past the top limb are discarded
+///
+/// # Implementation Details
+/// This function splits the shift into whole limbs and a remainder:
+/// 1. Divides `amount` into a limb offset `amount / 64` and a bit offset `amount % 64`
+/// 2. Fills the limbs from the most significant one down, so no source limb is overwritten early
+/// 3. Builds every output limb from its two source limbs with `ebm_funnel_shl`
+/// 4. Treats source limbs below the start of the slice as zero
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Linear time in the number of limbs, independent of the shift amount
+/// - One funnel shift per limb
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_shl_limbs;
+/// let mut a = [1, 0];
+/// ebm_shl_limbs(&mut a, 64); // a == [0, 1]
+/// let mut b = [1, 0];
+/// ebm_shl_limbs(&mut b, 65); // b == [0, 2]
+/// ```
+///
+/// # Function Logic
+/// Output limb `i` receives the bits of source limb `i - offset` shifted up, topped up with the
+/// high bits of limb `i - offset - 1`. Amounts of the full width or more clear the number, just
+/// like shifting a fixed-size integer all the way out.
+///
+/// # Safety Considerations
+/// - Works for any shift amount, including amounts beyond the total width
+/// - Only indexes inside the slice
+/// - No undefined behavior possible
+pub fn ebm_shl_limbs(limbs: &mut [u64], amount: u32) {
+    let limb_offset = (amount / 64) as usize;
+    let bit_offset = amount % 64;
+
+    // Walk downward so every source limb is read before it is overwritten
+    for index in (0..limbs.len()).rev() {
+        let hi = if index >= limb_offset { limbs[index - limb_offset] } else { 0 };
+        let lo = if index > limb_offset { limbs[index - limb_offset - 1] } else { 0 };
+        limbs[index] = ebm_funnel_shl(hi, lo, bit_offset);
+    }
+}
+
+/// Multiplies two limbs and adds two more, returning the double-width result
+///
+/// # Arguments
+/// * `a` - The first factor
+/// * `b` - The second factor
+/// * `c` - A limb to add, typically the existing limb of the accumulator
+/// * `carry` - A second limb to add, typically the carry from the previous column
+///
+/// # Returns
+/// * `(u64, u64)` - The low and high limbs of `a * b + c + carry`
+///
+/// # Implementation Details
+/// This function performs the fused step in 128-bit arithmetic:
+/// 1. Widens all four inputs to `u128`
+/// 2. Computes `a * b + c + carry`, which never exceeds `2^128 - 1`
+/// 3. Splits the result into its low and high 64 bits
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One widening multiply and two add-with-carry pairs on 64-bit targets
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_mul_add_carry;
+/// let result = ebm_mul_add_carry(1u64 << 32, 1u64 << 32, 1, 2); // (3, 1)
+/// let result = ebm_mul_add_carry(u64::MAX, u64::MAX, u64::MAX, u64::MAX); // (u64::MAX, u64::MAX)
+/// ```
+///
+/// # Function Logic
+/// Schoolbook multiplication adds every partial product into the accumulator together with the
+/// carry from the column below. Since `(2^64 - 1)^2 + 2 * (2^64 - 1) = 2^128 - 1`, the whole
+/// step fits in two limbs and the high limb becomes the carry for the next column.
+///
+/// # Safety Considerations
+/// - The 128-bit result cannot overflow for any inputs
+/// - No undefined behavior possible
+pub fn ebm_mul_add_carry(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let wide = u128::from(a) * u128::from(b) + u128::from(c) + u128::from(carry);
+    (wide as u64, (wide >> 64) as u64)
+}
+
+/// Multiplies two multi-precision numbers into a full-width product
+///
+/// # Arguments
+/// * `a` - The first factor as little-endian 64-bit limbs
+/// * `b` - The second factor as little-endian 64-bit limbs
+/// * `out` - The destination for the product, exactly `a.len() + b.len()` limbs long
+///
+/// # Implementation Details
+/// This function performs schoolbook multiplication row by row:
+/// 1. Checks that `out` has room for every limb of the product
+/// 2. Clears `out`, since every row accumulates into it
+/// 3. Multiplies each limb of `a` by every limb of `b` with `ebm_mul_add_carry`, adding the
+///    existing column and the carry from the column below
+/// 4. Stores the final carry of the row in the limb just above it
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Quadratic time: `a.len() * b.len()` multiply-accumulate steps
+/// - Sequential access within every row
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_mul_limbs;
+/// let mut out = [0u64; 3];
+/// ebm_mul_limbs(&[u64::MAX, 1], &[2], &mut out); // out == [u64::MAX - 1, 3, 0]
+/// let mut out = [0u64; 2];
+/// ebm_mul_limbs(&[u64::MAX], &[u64::MAX], &mut out); // out == [1, u64::MAX - 1]
+/// ```
+///
+/// # Function Logic
+/// The product of an `m`-limb and an `n`-limb number always fits in `m + n` limbs, so no carry
+/// is ever lost. Every limb of `out` is written, including the top limb when it is zero, and any
+/// previous contents are discarded.
+///
+/// # Safety Considerations
+/// - Panics when `out.len() != a.len() + b.len()`
+/// - The multiply-accumulate steps cannot overflow
+/// - No undefined behavior possible
+pub fn ebm_mul_limbs(a: &[u64], b: &[u64], out: &mut [u64]) {
+    // The product needs one limb per limb of both factors
+    assert_eq!(out.len(), a.len() + b.len(), "product needs a.len() + b.len() limbs");
+
+    out.fill(0);
+    for (index, &factor) in a.iter().enumerate() {
+        // Accumulate one row, shifted up by the position of the limb of a
+        let row = &mut out[index..=index + b.len()];
+        let mut carry = 0;
+        for (limb, &other) in row.iter_mut().zip(b) {
+            let (low, high) = ebm_mul_add_carry(factor, other, *limb, carry);
+            *limb = low;
+            carry = high;
+        }
+        row[b.len()] = carry;
+    }
+}
+
+/// Scales a value by a ratio without overflowing the intermediate product
+///
+/// # Arguments
+/// * `a` - The value to scale
+/// * `num` - The numerator of the ratio
+/// * `den` - The denominator of the ratio, must be non-zero
+///
+/// # Returns
+/// * `T` - `floor(a * num / den)`, saturated to `T::MAX` when the quotient does not fit
+///
+/// # Implementation Details
+/// This function carries out the computation in the double-width type:
+/// 1. Checks that the denominator is non-zero
+/// 2. Widens all three operands with `widen`, so the product `a * num` always fits
+/// 3. Divides the wide product by the wide denominator
+/// 4. Narrows the quotient back, or returns `T::MAX` when it is larger
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One widening multiply and one double-width division
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_mul_div;
+/// let result = ebm_mul_div(3_000_000_000u32, 3, 4); // 2_250_000_000 (a * num overflows u32)
+/// let result = ebm_mul_div(200u8, 200, 100); // 255 (400 saturates)
+/// let result = ebm_mul_div(7u64, 1, 2); // 3
+/// ```
+///
+/// # Function Logic
+/// Scaling by a fraction is usually written `a * num / den`, which overflows as soon as the
+/// product exceeds the type even though the result would fit. Since the product of two values
+/// always fits in twice their width, the division sees the exact product and the result is
+/// exact whenever it fits. Like the built-in division, a zero denominator is a programming
+/// error and panics; an oversized quotient saturates, matching how fixed-point scaling clamps.
+///
+/// # Safety Considerations
+/// - Panics when `den` is zero
+/// - The double-width product cannot overflow
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_mul_div<T: EbmWiden>(a: T, num: T, den: T) -> T {
+    // Dividing by zero has no meaningful result
+    assert!(den != T::ZERO, "denominator must be non-zero");
+
+    // The double-width product holds a * num exactly
+    let quotient = a.widen() * num.widen() / den.widen();
+    if quotient > T::MAX.widen() { T::MAX } else { quotient.narrow() }
+}
+
+/// Rounds a value up to the next multiple of an arbitrary positive number
+///
+/// # Arguments
+/// * `value` - The non-negative value to round up
+/// * `multiple` - The positive step to align to, not necessarily a power of two
+///
+/// # Returns
+/// * `T` - The smallest multiple of `multiple` that is greater than or equal to `value`
+///
+/// # Implementation Details
+/// This function computes `((value + multiple - 1) / multiple) * multiple` without its
+/// intermediate overflow:
+/// 1. Computes the remainder of `value` modulo `multiple`
+/// 2. Returns `value` unchanged when it already is a multiple
+/// 3. Otherwise adds the distance `multiple - remainder` to the next multiple
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One division for the remainder, one subtraction and one addition
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_round_up_to_multiple;
+/// let result = ebm_round_up_to_multiple(10u32, 7u32); // 14
+/// let result = ebm_round_up_to_multiple(14u32, 7u32); // 14 (already a multiple)
+/// let result = ebm_round_up_to_multiple(0u32, 7u32); // 0
+/// ```
+///
+/// # Function Logic
+/// Record offsets often have to be aligned to sizes such as 12 or 24 bytes, where the mask-based
+/// power-of-two rounding does not apply. The textbook formula adds `multiple - 1` first and can
+/// overflow for values near `T::MAX` even when the rounded result fits; adding only the missing
+/// distance overflows exactly when the result itself does not fit in `T`.
+///
+/// # Safety Considerations
+/// - `value` must not be negative and `multiple` must be positive (checked with a debug assertion)
+/// - Overflows when the rounded result exceeds `T::MAX`: panics in debug builds, wraps in release
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_round_up_to_multiple<T: EbmInt>(value: T, multiple: T) -> T {
+    // Rounding is only defined for a non-negative value and a positive step
+    debug_assert!(value >= T::ZERO && multiple > T::ZERO, "rounding needs a non-negative value and a positive multiple");
+
+    // Multiples need no rounding
+    let remainder = value % multiple;
+    if remainder == T::ZERO {
+        return value;
+    }
+
+    // Add the distance to the next multiple
+    value + (multiple - remainder)
+}
+
+/// Computes the floor of the n-th root of a non-negative integer with Newton's method
+///
+/// # Arguments
+/// * `a` - The non-negative value to take the root of
+/// * `n` - The degree of the root, at least 1
+///
+/// # Returns
+/// * `T` - The largest integer `x` with `x^n <= a`
+///
+/// # Implementation Details
+/// This function runs integer Newton iterations from above:
+/// 1. Returns `a` for `n == 1` and for `a` of 0 or 1
+/// 2. Returns 1 when `n` is at least the bit length of `a`, since then `2^n > a`
+/// 3. Starts from `2^ceil(bits(a) / n)`, which is never below the root
+/// 4. Computes `q = a / x^(n-1)` with `ebm_checked_pow`, treating an overflowing power as `q = 0`
+/// 5. Stops as soon as `q >= x`, otherwise steps to `x - ceil((x - q) / n)`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Quadratic convergence, a handful of iterations of one power and one division each
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_iroot;
+/// let result = ebm_iroot(1000u32, 3); // 10
+/// let result = ebm_iroot(999u32, 3); // 9
+/// let result = ebm_iroot(1u64 << 60, 4); // 32768
+/// ```
+///
+/// # Function Logic
+/// The Newton step `((n - 1) * x + a / x^(n-1)) / n` is rewritten as `x - (x - q) / n`, rounded
+/// so that it equals the floored textbook step. That form never multiplies `x` by `n - 1` and
+/// cannot overflow. Starting above the root, the iterates decrease monotonically and the first
+/// one whose step would not decrease is the floor of the root.
+///
+/// # Safety Considerations
+/// - `a` must not be negative and `n` must be at least 1 (checked with a debug assertion)
+/// - No overflow possible in any build mode
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_iroot<T: EbmInt>(a: T, n: u32) -> T {
+    // Roots are only defined for non-negative values and positive degrees
+    debug_assert!(a >= T::ZERO && n >= 1, "root needs a non-negative value and a positive degree");
+
+    // The first root, zero and one are their own roots
+    if n == 1 || a <= T::ONE {
+        return a;
+    }
+
+    // A degree of at least the bit length means 2^n > a, so the root is 1
+    let bits = T::BITS - a.leading_zeros();
+    if n >= bits {
+        return T::ONE;
+    }
+
+    // n is now smaller than the bit width and fits in T
+    let degree = T::from_u32(n);
+
+    // Start from a power of two at or above the root
+    let mut x = T::ONE << bits.div_ceil(n);
+    loop {
+        // An overflowing power means x^(n-1) > a
+        let q = match ebm_checked_pow(x, n - 1) {
+            Some(power) => a / power,
+            None => T::ZERO,
+        };
+
+        // Newton no longer decreases once x is the floor of the root
+        if q >= x {
+            return x;
+        }
+
+        // Step down by ceil((x - q) / n)
+        let d = x - q;
+        let step = if d % degree == T::ZERO { d / degree } else { d / degree + T::ONE };
+        x = x - step;
+    }
+}
+
+/// Computes the floor of the cube root of a non-negative integer
+///
+/// # Arguments
+/// * `a` - The non-negative value to take the cube root of
+///
+/// # Returns
+/// * `T` - The largest integer whose cube is less than or equal to `a`
+///
+/// # Implementation Details
+/// This function uses the binary digit-by-digit method, three bits of input per root bit:
+/// 1. Starts at the highest bit position `s` that is a multiple of three and inside the type
+/// 2. Doubles the partial root `y`, which appends a zero bit
+/// 3. Computes the cost of turning that bit into a one, `3y(y + 1) + 1`, scaled by `2^s`
+/// 4. Subtracts the cost from the remainder and sets the bit when the remainder covers it
+/// 5. Moves three bits down and repeats until `s` passes zero
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - About `width / 3` iterations of a few multiplications, shifts and comparisons
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_icbrt;
+/// let result = ebm_icbrt(27u32); // 3
+/// let result = ebm_icbrt(26u32); // 2
+/// let result = ebm_icbrt(0u32); // 0
+/// ```
+///
+/// # Function Logic
+/// `(y + 1)^3 - y^3 = 3y(y + 1) + 1`, so after doubling the partial root this term is exactly
+/// the amount its cube grows by when the new low bit is set, in units of the current group.
+/// Comparing against the remainder shifted down by `s`, rather than shifting the cost up, keeps
+/// every intermediate value inside the type.
+///
+/// # Safety Considerations
+/// - `a` must not be negative (checked with a debug assertion)
+/// - No overflow possible in any build mode
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_icbrt<T: EbmInt>(a: T) -> T {
+    // Negative values are not supported
+    debug_assert!(a >= T::ZERO, "cube root of a negative value");
+
+    let three = T::from_u32(3);
+    let mut remainder = a;
+    let mut root = T::ZERO;
+
+    // Walk the three-bit groups from the top of the type down
+    let mut shift = (T::BITS - 1) / 3 * 3;
+    loop {
+        // Append a zero bit to the root and price turning it into a one
+        root = root + root;
+        let cost = three * root * (root + T::ONE) + T::ONE;
+
+        // Set the bit when the remainder can pay for it
+        if (remainder >> shift) >= cost {
+            remainder = remainder - (cost << shift);
+            root = root + T::ONE;
+        }
+
+        if shift == 0 {
+            return root;
+        }
+        shift -= 3;
+    }
+}
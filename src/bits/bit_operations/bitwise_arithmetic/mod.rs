@@ -3,6 +3,8 @@
 // It is organized into basic operations and advanced operations
 
 // Import the basic bitwise arithmetic operations
+// The basic file shares the group name by design (see README file organization)
+#[allow(clippy::module_inception)]
 pub mod bitwise_arithmetic;
 
 // Import the advanced bitwise arithmetic operations
@@ -1,3 +1,125 @@
+// Bitwise Counting Advanced Leading Operations for Eidolon Math Library
+// This module contains advanced leading bit counting operations built on top of the basic functions
+// They provide portable fast paths for targets without a count-leading-zeros instruction
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
+// Import the basic operations these advanced functions are built on
+use crate::bits::bit_operations::bitwise_shifting::bitwise_shifting::{ebm_left_shift, ebm_right_shift};
 
+// Leading zero count of every 4-bit value
+const NIBBLE_LEADING_ZEROS: [u32; 16] = [4, 3, 2, 2, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0];
 
+/// Counts the leading zero bits of a `u32` with a binary search over nibbles
+///
+/// # Arguments
+/// * `a` - The value to scan
+///
+/// # Returns
+/// * `u32` - The number of leading zero bits, or 32 when `a` is zero
+///
+/// # Implementation Details
+/// This function narrows down the highest set bit in halving steps:
+/// 1. Returns 32 for zero, which has no set bit to locate
+/// 2. If the top 16 bits are clear, counts 16 and shifts the value up by 16
+/// 3. If the top 8 bits are now clear, counts 8 and shifts up by 8
+/// 4. If the top 4 bits are now clear, counts 4 and shifts up by 4
+/// 5. Looks up the leading zeros of the top nibble in a 16-entry table
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Three comparisons and one table lookup instead of up to 32 loop iterations
+/// - The comparisons compile to conditional moves on most targets
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_leading::ebm_clz_binary_search_u32;
+/// let result = ebm_clz_binary_search_u32(1); // 31
+/// let result = ebm_clz_binary_search_u32(0x0001_0000); // 15
+/// let result = ebm_clz_binary_search_u32(0); // 32
+/// ```
+///
+/// # Function Logic
+/// After every step the highest set bit lies in the top half of the remaining window, so three
+/// steps leave it in the top nibble. The final nibble is resolved with a lookup instead of two
+/// more comparisons. This is a fast replacement on targets without an LZCNT instruction.
+///
+/// # Safety Considerations
+/// - Uses only safe shifts with amounts below the bit width
+/// - Table indices are always below 16
+/// - No undefined behavior possible
+pub fn ebm_clz_binary_search_u32(a: u32) -> u32 {
+    // Zero has no highest set bit to locate
+    if a == 0 {
+        return 32;
+    }
+
+    let mut x = a;
+    let mut count = 0;
+
+    // Move the highest set bit into the top nibble in halving steps
+    if x <= 0x0000_FFFF {
+        count += 16;
+        x = ebm_left_shift(x, 16u32);
+    }
+    if x <= 0x00FF_FFFF {
+        count += 8;
+        x = ebm_left_shift(x, 8u32);
+    }
+    if x <= 0x0FFF_FFFF {
+        count += 4;
+        x = ebm_left_shift(x, 4u32);
+    }
+
+    // Resolve the remaining bits within the top nibble
+    count + NIBBLE_LEADING_ZEROS[ebm_right_shift(x, 28u32) as usize]
+}
+
+/// Counts the leading zero bits of a wide integer stored as 64-bit limbs
+///
+/// # Arguments
+/// * `limbs` - The integer, most significant limb first
+///
+/// # Returns
+/// * `u64` - The number of leading zero bits of the whole integer, or `64 * limbs.len()` when
+///   every limb is zero
+///
+/// # Implementation Details
+/// This function scans the limbs from the most significant end:
+/// 1. Skips every all-zero limb, which contributes 64 leading zeros
+/// 2. Adds the hardware leading-zero count of the first non-zero limb and stops
+/// 3. Returns the full width when no limb has a set bit
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Linear time in the number of leading zero limbs, stopping at the first non-zero one
+/// - Hardware-accelerated when possible (LZCNT instruction)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_leading::ebm_leading_zeros_limbs;
+/// let result = ebm_leading_zeros_limbs(&[0, 0x0000_FFFF_0000_0000, 7]); // 80
+/// let result = ebm_leading_zeros_limbs(&[1, 0]); // 63
+/// let result = ebm_leading_zeros_limbs(&[0, 0]); // 128
+/// ```
+///
+/// # Function Logic
+/// Normalizing a bignum for division or floating-point conversion needs its bit length, which
+/// is the total width minus this count. The limbs are taken in big-endian order, matching how
+/// the number is written, so the scan runs front to back.
+///
+/// # Safety Considerations
+/// - Uses only safe iteration over the slice
+/// - No undefined behavior possible
+pub fn ebm_leading_zeros_limbs(limbs: &[u64]) -> u64 {
+    let mut count = 0;
+
+    // Every zero limb on top adds its full width
+    for &limb in limbs {
+        if limb != 0 {
+            return count + limb.leading_zeros() as u64;
+        }
+        count += 64;
+    }
+    count
+}
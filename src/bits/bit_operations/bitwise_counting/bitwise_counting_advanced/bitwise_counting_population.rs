@@ -1,3 +1,196 @@
+// Bitwise Counting Advanced Population Operations for Eidolon Math Library
+// This module contains advanced population counting operations built on top of the basic functions
+// They provide portable SWAR fast paths for targets without a population count instruction
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
+// Import the integer trait that provides the hardware population count
+use crate::bits::ebm_int::EbmInt;
 
+// Import the lowest-set-bit reset used by the sparse count
+use crate::bits::bit_manipulation::ebm_blsr;
 
+// Import the basic operations these advanced functions are built on
+use crate::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic::{ebm_add, ebm_sub};
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::ebm_and;
+use crate::bits::bit_operations::bitwise_shifting::bitwise_shifting::ebm_right_shift;
+
+// Lane masks of the SWAR population count, replicated across 128 bits
+const PAIRS_U128: u128 = 0x5555_5555_5555_5555_5555_5555_5555_5555;
+const NIBBLE_PAIRS_U128: u128 = 0x3333_3333_3333_3333_3333_3333_3333_3333;
+const NIBBLES_U128: u128 = 0x0F0F_0F0F_0F0F_0F0F_0F0F_0F0F_0F0F_0F0F;
+const BYTE_ONES_U128: u128 = 0x0101_0101_0101_0101_0101_0101_0101_0101;
+
+/// Counts the set bits of a `u128` with the folded SWAR algorithm
+///
+/// # Arguments
+/// * `a` - The value whose set bits are counted
+///
+/// # Returns
+/// * `u32` - The number of set bits, between 0 and 128
+///
+/// # Implementation Details
+/// This function adds neighboring bit counts in ever wider lanes:
+/// 1. Stores the count of every 2-bit lane in place with `a - ((a >> 1) & 0x55..55)`
+/// 2. Adds neighboring 2-bit counts into 4-bit lanes with the `0x33..33` mask
+/// 3. Adds neighboring 4-bit counts into bytes with the `0x0F..0F` mask
+/// 4. Sums all sixteen byte counts into the top byte by multiplying with `0x01..01`
+/// 5. Shifts the top byte down to obtain the total
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - A fixed sequence of about a dozen operations, no loops or branches
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_population::ebm_popcount_u128;
+/// let result = ebm_popcount_u128(0xFF); // 8
+/// let result = ebm_popcount_u128(u128::MAX); // 128
+/// let result = ebm_popcount_u128(0); // 0
+/// ```
+///
+/// # Function Logic
+/// Every step works on all lanes of the word at once, so the number of steps grows with the
+/// logarithm of the width instead of the number of bits. No byte count can exceed 8 and the
+/// total cannot exceed 128, so none of the lane additions or the final multiplication carries
+/// into a neighboring lane that is still needed.
+///
+/// # Safety Considerations
+/// - The final multiplication deliberately wraps
+/// - No undefined behavior possible
+pub fn ebm_popcount_u128(a: u128) -> u32 {
+    // Count the bits of every 2-bit lane
+    let pairs = ebm_sub(a, ebm_and(ebm_right_shift(a, 1u32), PAIRS_U128));
+
+    // Add neighboring counts into 4-bit lanes, then into bytes
+    let nibbles = ebm_add(ebm_and(pairs, NIBBLE_PAIRS_U128), ebm_and(ebm_right_shift(pairs, 2u32), NIBBLE_PAIRS_U128));
+    let bytes = ebm_and(ebm_add(nibbles, ebm_right_shift(nibbles, 4u32)), NIBBLES_U128);
+
+    // Sum every byte count into the top byte
+    ebm_right_shift(bytes.wrapping_mul(BYTE_ONES_U128), 120u32) as u32
+}
+
+/// Counts the set bits of every byte in a buffer
+///
+/// # Arguments
+/// * `data` - The buffer to measure
+///
+/// # Returns
+/// * `Vec<u8>` - One entry per input byte holding its population count, between 0 and 8
+///
+/// # Implementation Details
+/// This function maps the byte population count over the buffer:
+/// 1. Allocates one output byte per input byte
+/// 2. Counts the set bits of each byte with `count_ones`, a single POPCNT where available
+/// 3. Narrows every count to a byte, which always fits since it is at most 8
+///
+/// # Performance Characteristics
+/// - One heap allocation of `data.len()` bytes
+/// - Linear time in the length of the buffer
+/// - Sequential reads and writes, easily vectorized by the compiler
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_population::ebm_popcount_per_byte;
+/// let result = ebm_popcount_per_byte(&[0x00, 0xFF, 0x0F]); // [0, 8, 4]
+/// let result = ebm_popcount_per_byte(&[]); // []
+/// ```
+///
+/// # Function Logic
+/// Plotting the bit density along a buffer makes structure visible: padding shows up as zeros,
+/// compressed or encrypted regions hover around 4. Unlike a single total, the per-byte counts
+/// keep the position of every measurement.
+///
+/// # Safety Considerations
+/// - Uses only safe iteration over the slice
+/// - No undefined behavior possible
+pub fn ebm_popcount_per_byte(data: &[u8]) -> Vec<u8> {
+    // Count every byte on its own; a byte has at most 8 set bits
+    data.iter().map(|&byte| byte.count_ones() as u8).collect()
+}
+
+/// Counts the set bits of a value that fall inside a mask
+///
+/// # Arguments
+/// * `a` - The value whose bits are counted
+/// * `mask` - The positions that take part in the count
+///
+/// # Returns
+/// * `u32` - The number of set bits in `a & mask`
+///
+/// # Implementation Details
+/// This function restricts the value before counting:
+/// 1. Clears every bit outside the mask with `ebm_and`
+/// 2. Counts the remaining bits with the hardware-accelerated `count_ones`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One AND and one population count
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_population::ebm_popcount_masked;
+/// let result = ebm_popcount_masked(0xFFu8, 0xF0); // 4
+/// let result = ebm_popcount_masked(0b1010_1010u8, 0x0F); // 2
+/// let result = ebm_popcount_masked(-1i16, 0); // 0
+/// ```
+///
+/// # Function Logic
+/// Flag words often group their bits into categories, each described by a mask. Counting the
+/// active flags of one category is then a masked population count.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in methods
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_popcount_masked<T: EbmInt>(a: T, mask: T) -> u32 {
+    // Count only the bits the mask selects
+    ebm_and(a, mask).count_ones()
+}
+
+/// Counts the set bits of a value by clearing them one at a time (Kernighan's method)
+///
+/// # Arguments
+/// * `a` - The value whose set bits are counted
+///
+/// # Returns
+/// * `u32` - The number of set bits, the same as `count_ones`
+///
+/// # Implementation Details
+/// This function loops once per set bit:
+/// 1. Stops as soon as the value is zero
+/// 2. Otherwise clears the lowest set bit with `ebm_blsr` and increments the count
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One AND, one decrement and one comparison per set bit, independent of the width
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_population::ebm_popcount_sparse;
+/// let result = ebm_popcount_sparse(0u64); // 0 (no iterations)
+/// let result = ebm_popcount_sparse(0x8000_0000_0000_0001u64); // 2 (two iterations)
+/// let result = ebm_popcount_sparse(-1i8); // 8
+/// ```
+///
+/// # Function Logic
+/// `a & (a - 1)` removes exactly the lowest set bit, so the loop runs once per set bit instead
+/// of once per bit position. For mostly-zero masks on targets without a population count
+/// instruction this beats the SWAR count of `ebm_popcount_u128`; for dense values the SWAR
+/// count, or the hardware `count_ones`, is faster.
+///
+/// # Safety Considerations
+/// - The decrement wraps, so every value including `T::MIN` terminates
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_popcount_sparse<T: EbmInt>(a: T) -> u32 {
+    let mut remaining = a;
+    let mut count = 0;
+
+    // Every iteration clears exactly one set bit
+    while remaining != T::ZERO {
+        remaining = ebm_blsr(remaining);
+        count += 1;
+    }
+    count
+}
@@ -1,3 +1,83 @@
+// Bitwise Counting Advanced Trailing Operations for Eidolon Math Library
+// This module contains advanced trailing bit counting operations built on top of the basic functions
+// They provide portable fast paths for targets without a count-trailing-zeros instruction
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
+// Import the basic operations these advanced functions are built on
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::ebm_and;
+use crate::bits::bit_operations::bitwise_shifting::bitwise_shifting::ebm_right_shift;
 
+// The de Bruijn constant B(2, 5): every 5-bit window of this 32-bit value, read from the top
+// while shifting it left, is distinct. Multiplying it by 2^n therefore places a unique 5-bit
+// pattern in the top bits for every n in 0..32, which the table below maps back to n.
+const DEBRUIJN_32: u32 = 0x077C_B531;
+
+// Maps the top 5 bits of DEBRUIJN_32 << n back to n, built at compile time
+const DEBRUIJN_32_TABLE: [u32; 32] = build_debruijn_32_table();
+
+// Builds the lookup table by recording the window produced by every shift amount
+const fn build_debruijn_32_table() -> [u32; 32] {
+    let mut table = [0u32; 32];
+    let mut n = 0;
+    while n < 32 {
+        table[((DEBRUIJN_32 << n) >> 27) as usize] = n;
+        n += 1;
+    }
+    table
+}
+
+/// Counts the trailing zero bits of a `u32` with a de Bruijn multiplication
+///
+/// # Arguments
+/// * `a` - The value to scan
+///
+/// # Returns
+/// * `u32` - The number of trailing zero bits, or 32 when `a` is zero
+///
+/// # Implementation Details
+/// This function replaces the bit scan instruction with one multiply and one table lookup:
+/// 1. Returns 32 for zero, which has no set bit to locate
+/// 2. Isolates the lowest set bit with `a & a.wrapping_neg()`, giving `2^n`
+/// 3. Multiplies the de Bruijn constant `0x077CB531` by `2^n`, which is a left shift by `n`
+/// 4. Uses the top 5 bits of the product as an index into a 32-entry table that returns `n`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One negation, one AND, one multiply, one shift and one lookup, no loops
+/// - The 128-byte table is built at compile time
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_trailing::ebm_debruijn_trailing_zeros_u32;
+/// let result = ebm_debruijn_trailing_zeros_u32(0b1000); // 3
+/// let result = ebm_debruijn_trailing_zeros_u32(0x8000_0000); // 31
+/// let result = ebm_debruijn_trailing_zeros_u32(0); // 32
+/// ```
+///
+/// # Function Logic
+/// `0x077CB531` is a de Bruijn sequence B(2, 5): reading its 32 bits as a cyclic string, every
+/// 5-bit pattern appears exactly once, and the constant starts with five zeros so that the
+/// windows seen while shifting left never wrap around. Shifting it left by `n` therefore leaves a
+/// unique pattern in the top 5 bits, so the table lookup recovers the position of the isolated
+/// bit. This is a fast, branch-light alternative on microcontrollers that lack a
+/// count-trailing-zeros instruction.
+///
+/// # Safety Considerations
+/// - The multiplication deliberately wraps
+/// - Table indices are always below 32
+/// - No undefined behavior possible
+pub fn ebm_debruijn_trailing_zeros_u32(a: u32) -> u32 {
+    // Zero has no lowest set bit to isolate
+    if a == 0 {
+        return 32;
+    }
+
+    // Keep only the lowest set bit, which turns the multiply into a shift
+    let lowest = ebm_and(a, a.wrapping_neg());
+
+    // The top 5 bits of the product identify the shift amount
+    let index = ebm_right_shift(lowest.wrapping_mul(DEBRUIJN_32), 27u32);
+    DEBRUIJN_32_TABLE[index as usize]
+}
 
@@ -1,3 +1,500 @@
+// Bitwise Counting Other Related Operations for Eidolon Math Library
+// This module contains counting helpers that are not built on the basic counting functions
+// They rely on the hardware-accelerated counting intrinsics exposed through the EbmInt trait
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
+// Import the integer trait that provides the counting intrinsics
+use crate::bits::ebm_int::EbmInt;
 
+// Import the bit manipulation helpers used for preconditions and subset masks
+use crate::bits::bit_manipulation::{ebm_is_single_bit, ebm_low_mask};
 
+// Import the XOR that marks the positions where two values differ
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::ebmxor;
+
+/// Returns whether the first value has more set bits than the second
+///
+/// # Arguments
+/// * `a` - The first operand to compare
+/// * `b` - The second operand to compare
+///
+/// # Returns
+/// * `bool` - `true` if `a` has strictly more set bits than `b`
+///
+/// # Implementation Details
+/// This function compares the population counts of both operands:
+/// 1. Counts the set bits of each operand with the `count_ones` intrinsic
+/// 2. Compares the two counts
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Hardware-accelerated when possible (POPCNT instruction)
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_has_more_bits;
+/// let result = ebm_has_more_bits(0b0111u8, 0b1001u8); // true (3 > 2)
+/// let result = ebm_has_more_bits(0b0011u8, 0b1100u8); // false (2 == 2)
+/// let result = ebm_has_more_bits(-1i8, 0x7Fi8); // true (8 > 7)
+/// ```
+///
+/// # Function Logic
+/// Ranking binary feature vectors by density compares their Hamming weights. This predicate
+/// names that comparison instead of repeating two population counts at every call site.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in methods
+/// - No undefined behavior possible with valid numeric types
+/// - Signed values are counted by their two's-complement bit pattern
+pub fn ebm_has_more_bits<T: EbmInt>(a: T, b: T) -> bool {
+    // Compare the Hamming weights of both operands
+    a.count_ones() > b.count_ones()
+}
+
+/// Returns whether two values have the same number of set bits
+///
+/// # Arguments
+/// * `a` - The first operand to compare
+/// * `b` - The second operand to compare
+///
+/// # Returns
+/// * `bool` - `true` if `a` and `b` have equal population counts
+///
+/// # Implementation Details
+/// This function compares the population counts of both operands:
+/// 1. Counts the set bits of each operand with the `count_ones` intrinsic
+/// 2. Checks the two counts for equality
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Hardware-accelerated when possible (POPCNT instruction)
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_same_bit_count;
+/// let result = ebm_same_bit_count(0b0011u8, 0b1100u8); // true
+/// let result = ebm_same_bit_count(0b0111u8, 0b1001u8); // false
+/// ```
+///
+/// # Function Logic
+/// Two values with equal Hamming weight are equally dense even when their bits sit at different
+/// positions. This is the equality counterpart of `ebm_has_more_bits`.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in methods
+/// - No undefined behavior possible with valid numeric types
+/// - Signed values are counted by their two's-complement bit pattern
+pub fn ebm_same_bit_count<T: EbmInt>(a: T, b: T) -> bool {
+    // Compare the Hamming weights of both operands
+    a.count_ones() == b.count_ones()
+}
+
+/// Counts the differing bits of two byte slices, restricted to the positions selected by a mask
+///
+/// # Arguments
+/// * `a` - The first byte buffer to compare
+/// * `b` - The second byte buffer to compare
+/// * `mask` - The buffer selecting which bit positions take part in the comparison
+///
+/// # Returns
+/// * `u64` - The number of bit positions where `a` and `b` differ and `mask` is set
+///
+/// # Implementation Details
+/// This function accumulates a masked Hamming distance byte by byte:
+/// 1. Checks that all three slices have the same length
+/// 2. XORs corresponding bytes of `a` and `b` to mark the differing bits
+/// 3. ANDs the differences with the mask to drop don't-care positions
+/// 4. Sums the population counts of the remaining bits
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Linear time in the length of the buffers
+/// - Hardware-accelerated when possible (POPCNT instruction)
+/// - Cache-friendly sequential access of all three buffers
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_masked_hamming;
+/// let result = ebm_masked_hamming(&[0xFF], &[0x00], &[0x0F]); // 4 (only the low nibble counts)
+/// let result = ebm_masked_hamming(&[0xAA, 0x01], &[0x55, 0x01], &[0xFF, 0xFF]); // 8
+/// ```
+///
+/// # Function Logic
+/// The result is `popcount((a ^ b) & mask)` summed over the buffers. Template matching uses the
+/// mask to ignore don't-care positions, so only the relevant bits contribute to the distance.
+///
+/// # Safety Considerations
+/// - Panics when the slices have different lengths
+/// - Uses only safe iteration over the slices
+/// - No undefined behavior possible
+pub fn ebm_masked_hamming(a: &[u8], b: &[u8], mask: &[u8]) -> u64 {
+    // All three buffers must describe the same bit positions
+    assert_eq!(a.len(), b.len(), "slices to compare must have equal length");
+    assert_eq!(a.len(), mask.len(), "mask must have the same length as the compared slices");
+
+    // Count the differing bits that fall inside the mask
+    a.iter()
+        .zip(b)
+        .zip(mask)
+        .map(|((&left, &right), &select)| ((left ^ right) & select).count_ones() as u64)
+        .sum()
+}
+
+/// Counts the set bits and returns the count in the same type as the input
+///
+/// # Arguments
+/// * `a` - The value whose set bits are counted
+///
+/// # Returns
+/// * `T` - The number of set bits, converted to `T`
+///
+/// # Implementation Details
+/// This function converts the intrinsic count into the operand type:
+/// 1. Counts the set bits with the `count_ones` intrinsic
+/// 2. Converts the `u32` count with `EbmInt::from_u32`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Hardware-accelerated when possible (POPCNT instruction)
+/// - The conversion compiles to nothing or a single truncation
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_count_ones_as;
+/// let result = ebm_count_ones_as(0xF0u8); // 4u8
+/// let result = ebm_count_ones_as(-1i64); // 64i64
+/// let result = ebm_count_ones_as(0x0101u16) * 2; // 4u16, no cast needed
+/// ```
+///
+/// # Function Logic
+/// The `u32` returned by `count_ones` has to be cast before it can take part in arithmetic
+/// with the operand type. Returning the count as `T` lets generic code use it directly. The
+/// conversion can never overflow: the count is at most the bit width, and even `i8` can hold 8.
+///
+/// # Safety Considerations
+/// - The count never exceeds the bit width, so the conversion is lossless for every type
+/// - No undefined behavior possible
+pub fn ebm_count_ones_as<T: EbmInt>(a: T) -> T {
+    // The count is at most the bit width, which fits in every integer type
+    T::from_u32(a.count_ones())
+}
+
+/// Returns the index of the only set bit of a one-hot value
+///
+/// # Arguments
+/// * `a` - A value with exactly one bit set
+///
+/// # Returns
+/// * `u32` - The position of the set bit, which is `log2(a)` for unsigned values
+///
+/// # Implementation Details
+/// This function relies on the one-hot precondition:
+/// 1. Checks that exactly one bit is set with `ebm_is_single_bit` in debug builds
+/// 2. Counts the trailing zeros with the `trailing_zeros` intrinsic
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Hardware-accelerated when possible (TZCNT/BSF instruction)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_log2_exact;
+/// let result = ebm_log2_exact(0b0001_0000u8); // 4
+/// let result = ebm_log2_exact(1u32); // 0
+/// let result = ebm_log2_exact(i64::MIN); // 63
+/// ```
+///
+/// # Function Logic
+/// For a one-hot value the number of trailing zeros is the index of its bit, which avoids the
+/// width-dependent `width - 1 - leading_zeros` of the general logarithm. This decodes one-hot
+/// state encodings directly into state numbers.
+///
+/// # Safety Considerations
+/// - `a` must have exactly one bit set (checked with a debug assertion)
+/// - Returns the lowest set bit for invalid inputs in release builds, or the width for zero
+/// - No undefined behavior possible
+pub fn ebm_log2_exact<T: EbmInt>(a: T) -> u32 {
+    // The result is only meaningful for one-hot values
+    debug_assert!(ebm_is_single_bit(a), "value must have exactly one bit set");
+
+    // The only set bit sits above all trailing zeros
+    a.trailing_zeros()
+}
+
+// Returns the next larger value with the same number of set bits (Gosper's hack)
+fn next_same_popcount(x: u64) -> u64 {
+    // Move the lowest block of ones up by one position...
+    let lowest = x & x.wrapping_neg();
+    let ripple = x + lowest;
+
+    // ...and refill the vacated bits at the bottom with the ones that were lost
+    (((ripple ^ x) >> 2) / lowest) | ripple
+}
+
+/// Iterates over all `n`-bit values with exactly `k` set bits in increasing order
+///
+/// # Arguments
+/// * `n` - The number of available bit positions, at most 64
+/// * `k` - The number of set bits in every value
+///
+/// # Returns
+/// * `impl Iterator<Item = u64>` - The `C(n, k)` subsets as bit masks, smallest first
+///
+/// # Implementation Details
+/// This function walks the subsets with Gosper's hack (snoob, "same number of one bits"):
+/// 1. Starts with the `k` lowest bits set, the smallest subset
+/// 2. Computes each successor by moving the lowest block of ones up by one position and
+///    refilling the vacated bits at the bottom
+/// 3. Stops after the `k` highest of the `n` bits, the largest subset
+/// 4. Yields nothing when `k > n` and a single zero when `k == 0`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Constant time per subset: one negation, one addition, one division and a few logic operations
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_combinations;
+/// let subsets: Vec<u64> = ebm_combinations(4, 2).collect(); // [0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]
+/// let count = ebm_combinations(64, 1).count(); // 64
+/// ```
+///
+/// # Function Logic
+/// Every value with `k` set bits is a `k`-subset of `n` elements, and the successor computed by
+/// Gosper's hack is the next larger such value. The largest subset is compared against
+/// explicitly, so the step never runs past bit 63 even when `n == 64`.
+///
+/// # Safety Considerations
+/// - Panics when `n` exceeds 64, in every build mode, since larger sets cannot be represented
+/// - The successor is only computed below the largest subset, where it cannot overflow
+/// - No undefined behavior possible
+pub fn ebm_combinations(n: u32, k: u32) -> impl Iterator<Item = u64> {
+    // Subsets are represented as 64-bit masks
+    assert!(n <= 64, "at most 64 elements are supported");
+
+    // The smallest subset uses the lowest bits, the largest one the highest bits
+    let first = if k <= n { Some(ebm_low_mask::<u64>(k)) } else { None };
+    let last = ebm_low_mask::<u64>(k).checked_shl(n.saturating_sub(k)).unwrap_or(0);
+
+    std::iter::successors(first, move |&current| {
+        if current == last {
+            None
+        } else {
+            Some(next_same_popcount(current))
+        }
+    })
+}
+
+/// Counts the bits set in both of two bitmaps (intersection cardinality)
+///
+/// # Arguments
+/// * `a` - The first bitmap, packed into 64-bit words
+/// * `b` - The second bitmap, packed the same way
+///
+/// # Returns
+/// * `u64` - The number of bit positions set in both bitmaps
+///
+/// # Implementation Details
+/// This function reduces the bitmaps word by word:
+/// 1. Checks that both slices have the same length
+/// 2. ANDs corresponding words to keep the shared bits
+/// 3. Sums the population counts of the results
+///
+/// # Performance Characteristics
+/// - Zero heap allocations, no intermediate bitmap is built
+/// - Linear time in the length of the bitmaps
+/// - Hardware-accelerated when possible (POPCNT instruction)
+/// - Cache-friendly sequential access of both bitmaps
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_and_popcount;
+/// let result = ebm_and_popcount(&[0b1100, 0xFF], &[0b1010, 0x0F]); // 5
+/// let result = ebm_and_popcount(&[], &[]); // 0
+/// ```
+///
+/// # Function Logic
+/// For 0/1 feature vectors stored as bitmaps this is the dot product, and for sets stored as
+/// bitmaps it is the size of the intersection. It is the numerator of the Jaccard similarity.
+///
+/// # Safety Considerations
+/// - Panics when the slices have different lengths
+/// - Uses only safe iteration over the slices
+/// - No undefined behavior possible
+pub fn ebm_and_popcount(a: &[u64], b: &[u64]) -> u64 {
+    // Both bitmaps must describe the same bit positions
+    assert_eq!(a.len(), b.len(), "bitmaps must have equal length");
+
+    // Count the shared bits word by word
+    a.iter().zip(b).map(|(&x, &y)| (x & y).count_ones() as u64).sum()
+}
+
+/// Counts the bits set in either of two bitmaps (union cardinality)
+///
+/// # Arguments
+/// * `a` - The first bitmap, packed into 64-bit words
+/// * `b` - The second bitmap, packed the same way
+///
+/// # Returns
+/// * `u64` - The number of bit positions set in at least one of the bitmaps
+///
+/// # Implementation Details
+/// This function reduces the bitmaps word by word:
+/// 1. Checks that both slices have the same length
+/// 2. ORs corresponding words to combine their bits
+/// 3. Sums the population counts of the results
+///
+/// # Performance Characteristics
+/// - Zero heap allocations, no intermediate bitmap is built
+/// - Linear time in the length of the bitmaps
+/// - Hardware-accelerated when possible (POPCNT instruction)
+/// - Cache-friendly sequential access of both bitmaps
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_or_popcount;
+/// let result = ebm_or_popcount(&[0b1100, 0xFF], &[0b1010, 0x0F]); // 11
+/// let result = ebm_or_popcount(&[], &[]); // 0
+/// ```
+///
+/// # Function Logic
+/// This is the size of the union of two sets stored as bitmaps, the denominator of the Jaccard
+/// similarity. Together with `ebm_and_popcount` it gives both terms in one pass each, and the
+/// two are related by `|a| + |b| = |a & b| + |a | b|`.
+///
+/// # Safety Considerations
+/// - Panics when the slices have different lengths
+/// - Uses only safe iteration over the slices
+/// - No undefined behavior possible
+pub fn ebm_or_popcount(a: &[u64], b: &[u64]) -> u64 {
+    // Both bitmaps must describe the same bit positions
+    assert_eq!(a.len(), b.len(), "bitmaps must have equal length");
+
+    // Count the combined bits word by word
+    a.iter().zip(b).map(|(&x, &y)| (x | y).count_ones() as u64).sum()
+}
+
+/// Returns the minimum number of bits needed to represent a value
+///
+/// # Arguments
+/// * `a` - The value to measure
+///
+/// # Returns
+/// * `u32` - The position of the highest set bit plus one, or 0 when `a` is zero
+///
+/// # Implementation Details
+/// This function derives the length from the leading zero count:
+/// 1. Counts the leading zeros with the hardware-accelerated `leading_zeros` of `EbmInt`
+/// 2. Subtracts them from the bit width, which leaves 0 for zero
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One leading-zero count and one subtraction (LZCNT where available)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_bit_length;
+/// let result = ebm_bit_length(0u8); // 0
+/// let result = ebm_bit_length(1u8); // 1
+/// let result = ebm_bit_length(255u8); // 8
+/// let result = ebm_bit_length(256u32); // 9
+/// ```
+///
+/// # Function Logic
+/// Variable-length encodings size each value by its bit length, e.g. a LEB128 varint needs
+/// `ceil(bit_length / 7)` bytes. Negative signed values have their sign bit set and therefore
+/// always report the full width.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in methods
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_bit_length<T: EbmInt>(a: T) -> u32 {
+    // Everything below the highest set bit, and the bit itself, is needed
+    T::BITS - a.leading_zeros()
+}
+
+/// Counts the bit positions where two values agree
+///
+/// # Arguments
+/// * `a` - The first value
+/// * `b` - The second value
+///
+/// # Returns
+/// * `u32` - The number of positions where both bits are 0 or both are 1
+///
+/// # Implementation Details
+/// This function subtracts the Hamming distance from the width:
+/// 1. Computes `a ^ b` with `ebmxor`, which has a set bit exactly where the values differ
+/// 2. Counts the differing positions with the hardware-accelerated `count_ones`
+/// 3. Subtracts that count from the bit width
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One XOR, one population count and one subtraction
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_agreement;
+/// let result = ebm_agreement(0b1100u8, 0b1010u8); // 6
+/// let result = ebm_agreement(0x5Au8, 0x5Au8); // 8
+/// let result = ebm_agreement(0x5Au8, 0xA5u8); // 0
+/// ```
+///
+/// # Function Logic
+/// Simple matching similarity counts shared zeros as matches too, unlike `ebm_and_popcount`,
+/// which only counts shared ones. Dividing the result by the width gives the matching
+/// coefficient of two binary vectors.
+///
+/// # Safety Considerations
+/// - The population count never exceeds the width, so the subtraction cannot underflow
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_agreement<T: EbmInt>(a: T, b: T) -> u32 {
+    // Every position that does not differ agrees
+    T::BITS - ebmxor(a, b).count_ones()
+}
+
+/// Returns the parity of a value, i.e. whether it has an odd number of set bits
+///
+/// # Arguments
+/// * `a` - The value whose set bits are counted
+///
+/// # Returns
+/// * `bool` - `true` if `a` has an odd number of set bits
+///
+/// # Implementation Details
+/// This function reduces the population count to its lowest bit:
+/// 1. Counts the set bits with the hardware-accelerated `count_ones`
+/// 2. Tests the lowest bit of the count
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One population count and one AND (the parity flag on some targets)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_parity;
+/// let result = ebm_parity(0b0111u8); // true
+/// let result = ebm_parity(0b0011u8); // false
+/// let result = ebm_parity(0u64); // false
+/// ```
+///
+/// # Function Logic
+/// Parity is the XOR of all bits. It is the check bit of parity framing and Hamming codes and
+/// the feedback bit of a Fibonacci LFSR, all of which share this function.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in methods
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_parity<T: EbmInt>(a: T) -> bool {
+    // Only the lowest bit of the count decides between odd and even
+    a.count_ones() & 1 == 1
+}
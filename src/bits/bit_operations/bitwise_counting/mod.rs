@@ -3,6 +3,9 @@
 // It is organized into basic operations and advanced operations
 
 // Import the basic bitwise counting operations
+// The basic file shares the group name by design (see README file organization)
+// Its placeholder counters do not read their argument yet
+#[allow(clippy::module_inception, unused_variables)]
 pub mod bitwise_counting;
 
 // Import the advanced bitwise counting operations
@@ -1,3 +1,100 @@
+// Bitwise Logic Advanced AND Operations for Eidolon Math Library
+// This module contains advanced operations built on top of the basic bitwise AND function
+// They combine AND with generated masks to clear and test individual bits and bit groups
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
+// Import the integer trait that provides the bit width and constants
+use crate::bits::ebm_int::EbmInt;
 
+// Import the basic AND and NOT operations these advanced functions are built on
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::{ebm_and, ebmnot};
 
+/// Clears the bit at the given position to 0
+///
+/// # Arguments
+/// * `a` - The operand to modify
+/// * `index` - The position of the bit to clear (0 is the least significant bit)
+///
+/// # Returns
+/// * `T` - The operand with the bit at `index` cleared
+///
+/// # Implementation Details
+/// This function clears a single bit using the basic AND and NOT operations:
+/// 1. Builds a single-bit mask by shifting 1 to the requested position
+/// 2. Inverts the mask with `ebmnot` so only that position is 0
+/// 3. Combines the operand and the inverted mask with `ebm_and`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One shift, one NOT and one AND, fully branchless
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_and::ebm_clear_bit;
+/// let result = ebm_clear_bit(0xFFu8, 0); // 0xFE
+/// let result = ebm_clear_bit(0x0Fu8, 7); // 0x0F (already clear)
+/// let result = ebm_clear_bit(-1i8, 7); // 127 (sign bit cleared)
+/// ```
+///
+/// # Function Logic
+/// AND-ing with a mask that has a single 0 forces that bit to 0 while every 1 in the mask keeps
+/// the original bit. This is the counterpart of `ebm_set_bit`.
+///
+/// # Safety Considerations
+/// - `index` must be smaller than the bit width (checked with a debug assertion)
+/// - No undefined behavior possible with valid numeric types
+/// - Compiler ensures type safety at compile time
+pub fn ebm_clear_bit<T: EbmInt>(a: T, index: u32) -> T {
+    // The bit position must exist inside the type
+    debug_assert!(index < T::BITS, "bit index out of range");
+
+    // Force the selected bit to 0 with an inverted single-bit mask
+    ebm_and(a, ebmnot(T::ONE << index))
+}
+
+/// Tests whether the bit at the given position is set
+///
+/// # Arguments
+/// * `a` - The operand to inspect
+/// * `index` - The position of the bit to test (0 is the least significant bit)
+///
+/// # Returns
+/// * `bool` - `true` if the bit at `index` is 1, `false` otherwise
+///
+/// # Implementation Details
+/// This function tests a single bit using the basic AND operation:
+/// 1. Builds a single-bit mask by shifting 1 to the requested position
+/// 2. Isolates the bit with `ebm_and`
+/// 3. Compares the isolated bit against zero
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One shift, one AND and one comparison
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_and::ebm_test_bit;
+/// let result = ebm_test_bit(0b0000_0100u8, 2); // true
+/// let result = ebm_test_bit(0b0000_0100u8, 3); // false
+/// let result = ebm_test_bit(-1i16, 15); // true (the sign bit)
+/// ```
+///
+/// # Function Logic
+/// AND-ing with a single-bit mask clears every other bit, so the result is non-zero exactly when
+/// the selected bit is set. This is the read side of `ebm_set_bit` and `ebm_clear_bit`.
+///
+/// # Safety Considerations
+/// - `index` must be smaller than the bit width (checked with a debug assertion)
+/// - No undefined behavior possible with valid numeric types
+/// - Compiler ensures type safety at compile time
+pub fn ebm_test_bit<T: EbmInt>(a: T, index: u32) -> bool {
+    // The bit position must exist inside the type
+    debug_assert!(index < T::BITS, "bit index out of range");
+
+    // Isolate the selected bit and check whether anything is left
+    ebm_and(a, T::ONE << index) != T::ZERO
+}
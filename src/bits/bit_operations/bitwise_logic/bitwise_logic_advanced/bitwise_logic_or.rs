@@ -1,3 +1,55 @@
+// Bitwise Logic Advanced OR Operations for Eidolon Math Library
+// This module contains advanced operations built on top of the basic bitwise OR function
+// They combine OR with generated masks to set individual bits and bit groups
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
+// Import the integer trait that provides the bit width and constants
+use crate::bits::ebm_int::EbmInt;
 
+// Import the basic OR operation these advanced functions are built on
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::ebmor;
 
+/// Sets the bit at the given position to 1
+///
+/// # Arguments
+/// * `a` - The operand to modify
+/// * `index` - The position of the bit to set (0 is the least significant bit)
+///
+/// # Returns
+/// * `T` - The operand with the bit at `index` set
+///
+/// # Implementation Details
+/// This function sets a single bit using the basic OR operation:
+/// 1. Builds a single-bit mask by shifting 1 to the requested position
+/// 2. Combines the operand and the mask with `ebmor`
+/// 3. Leaves every other bit untouched
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One shift and one OR, fully branchless
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_or::ebm_set_bit;
+/// let result = ebm_set_bit(0u8, 3); // 0b0000_1000
+/// let result = ebm_set_bit(0x0Fu8, 2); // 0x0F (already set)
+/// let result = ebm_set_bit(0i8, 7); // -128 (the sign bit)
+/// ```
+///
+/// # Function Logic
+/// OR-ing with a mask that has a single 1 forces that bit to 1 while every 0 in the mask keeps
+/// the original bit. This is the basic building block for flag words and packed bit sets.
+///
+/// # Safety Considerations
+/// - `index` must be smaller than the bit width (checked with a debug assertion)
+/// - No undefined behavior possible with valid numeric types
+/// - Compiler ensures type safety at compile time
+pub fn ebm_set_bit<T: EbmInt>(a: T, index: u32) -> T {
+    // The bit position must exist inside the type
+    debug_assert!(index < T::BITS, "bit index out of range");
+
+    // Force the selected bit to 1 with a single-bit mask
+    ebmor(a, T::ONE << index)
+}
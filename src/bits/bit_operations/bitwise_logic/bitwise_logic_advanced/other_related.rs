@@ -1,3 +1,46 @@
+// Bitwise Logic Other Related Operations for Eidolon Math Library
+// This module contains logic-based helpers that are not built on the basic logic functions
+// They cover reductions such as checksums that fold whole buffers with logical operators
+// Byte buffers are processed one element at a time from the start of the slice
 
-
-
+/// Computes the XOR checksum (longitudinal redundancy check) of a byte slice
+///
+/// # Arguments
+/// * `data` - The message bytes to checksum
+///
+/// # Returns
+/// * `u8` - The XOR of every byte in the slice, or 0 for an empty slice
+///
+/// # Implementation Details
+/// This function folds the buffer with the XOR operator:
+/// 1. Starts from 0, the identity element of XOR
+/// 2. XORs every byte into the running checksum
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Linear time in the length of the buffer
+/// - Easily vectorized by the compiler
+/// - Cache-friendly sequential access of the buffer
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::other_related::ebm_xor_checksum;
+/// let result = ebm_xor_checksum(b"Hello"); // 0x42
+/// let result = ebm_xor_checksum(&[0x01, 0x02, 0x03]); // 0x00
+/// let result = ebm_xor_checksum(&[]); // 0x00
+/// ```
+///
+/// # Function Logic
+/// The longitudinal redundancy check is the classic checksum of many serial protocols. Every bit
+/// of the result is the parity of that bit position across the message, so any single-bit error
+/// changes the checksum. Appending the checksum to the message makes the checksum of the whole
+/// frame zero.
+///
+/// # Safety Considerations
+/// - Uses only safe iteration over the slice
+/// - No undefined behavior possible
+/// - Not a cryptographic integrity check
+pub fn ebm_xor_checksum(data: &[u8]) -> u8 {
+    // XOR every byte into the running checksum, starting from the identity
+    data.iter().fold(0u8, |checksum, &byte| checksum ^ byte)
+}
@@ -4,7 +4,10 @@
 // Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
 // Import necessary standard library components for low-level operations
-use std::ops::{Shl, Shr, BitOr};
+use std::ops::{Shl, Shr};
+
+// Import the integer trait that provides the hardware rotate intrinsics
+use crate::bits::ebm_int::EbmInt;
 
 /// Performs a bitwise left shift operation on a value of generic type T
 /// 
@@ -131,12 +134,12 @@ where
 /// * `T` - The result of the left rotation operation
 /// 
 /// # Implementation Details
-/// This function implements left rotation using Rust's built-in shift operators and bitwise OR:
+/// This function uses the built-in `rotate_left()` method of the type through `EbmInt`:
 /// 1. Calculates the effective rotation amount within the type's bit size
-/// 2. Performs left shift on the original value
-/// 3. Performs right shift on the original value with complementary amount
-/// 4. Combines the results using bitwise OR
-/// 5. Handles all numeric types uniformly and safely
+/// 2. Compiled to a single ROL instruction on most architectures
+/// 3. Rotates the raw bit pattern, so signed types rotate exactly like unsigned ones
+/// 4. Rotating by zero or a multiple of the width returns the value unchanged
+/// 5. Handles all numeric types uniformly and safely, including `usize` on any platform
 /// 
 /// # Performance Characteristics
 /// - Zero heap allocations
@@ -167,20 +170,16 @@ where
 /// - Automatically handles rotation amounts larger than the type size
 pub fn ebm_left_rotate<T, U>(a: T, rotate_amount: U) -> T
 where
-    T: Shl<u32, Output = T> + Shr<u32, Output = T> + BitOr<Output = T> + Copy,
+    T: EbmInt,
     U: Into<u32> + Copy
 {
     // Calculate the effective rotation amount within the type's bit size
-    let bit_size = std::mem::size_of::<T>() as u32 * 8;
-    let effective_rotate = rotate_amount.into() % bit_size;
-    
-    // Perform left rotation using shift and OR operations
-    // Left shift by the rotation amount
-    let left_part = a << effective_rotate;
-    // Right shift by the complementary amount
-    let right_part = a >> (bit_size - effective_rotate);
-    // Combine using bitwise OR
-    left_part | right_part
+    let effective_rotate = rotate_amount.into() % T::BITS;
+
+    // Use the hardware rotate instead of combining two shifts
+    // Two shifts need a complementary shift by the full width for a zero amount, which overflows,
+    // and the right shift sign-fills signed values, which corrupts the rotated bits
+    a.rotate_left(effective_rotate)
 }
 
 /// Performs a bitwise right rotation operation on a value of generic type T
@@ -193,12 +192,12 @@ where
 /// * `T` - The result of the right rotation operation
 /// 
 /// # Implementation Details
-/// This function implements right rotation using Rust's built-in shift operators and bitwise OR:
+/// This function uses the built-in `rotate_right()` method of the type through `EbmInt`:
 /// 1. Calculates the effective rotation amount within the type's bit size
-/// 2. Performs right shift on the original value
-/// 3. Performs left shift on the original value with complementary amount
-/// 4. Combines the results using bitwise OR
-/// 5. Handles all numeric types uniformly and safely
+/// 2. Compiled to a single ROR instruction on most architectures
+/// 3. Rotates the raw bit pattern, so signed types rotate exactly like unsigned ones
+/// 4. Rotating by zero or a multiple of the width returns the value unchanged
+/// 5. Handles all numeric types uniformly and safely, including `usize` on any platform
 /// 
 /// # Performance Characteristics
 /// - Zero heap allocations
@@ -229,20 +228,16 @@ where
 /// - Automatically handles rotation amounts larger than the type size
 pub fn ebm_right_rotate<T, U>(a: T, rotate_amount: U) -> T
 where
-    T: Shl<u32, Output = T> + Shr<u32, Output = T> + BitOr<Output = T> + Copy,
+    T: EbmInt,
     U: Into<u32> + Copy
 {
     // Calculate the effective rotation amount within the type's bit size
-    let bit_size = std::mem::size_of::<T>() as u32 * 8;
-    let effective_rotate = rotate_amount.into() % bit_size;
-    
-    // Perform right rotation using shift and OR operations
-    // Right shift by the rotation amount
-    let right_part = a >> effective_rotate;
-    // Left shift by the complementary amount
-    let left_part = a << (bit_size - effective_rotate);
-    // Combine using bitwise OR
-    right_part | left_part
+    let effective_rotate = rotate_amount.into() % T::BITS;
+
+    // Use the hardware rotate instead of combining two shifts
+    // Two shifts need a complementary shift by the full width for a zero amount, which overflows,
+    // and the right shift sign-fills signed values, which corrupts the rotated bits
+    a.rotate_right(effective_rotate)
 }
 
 
@@ -1,3 +1,139 @@
+// Bitwise Shifting Advanced Rotate Operations for Eidolon Math Library
+// This module contains advanced rotate operations built on top of the basic shifting functions
+// They emulate rotations on bit fields narrower than the full type width
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
+// Import the integer trait that provides the bit width and constants
+use crate::bits::ebm_int::EbmInt;
 
+// Import the basic operations these advanced functions are built on
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::{ebm_and, ebmor};
+use crate::bits::bit_operations::bitwise_shifting::bitwise_shifting::{ebm_left_shift, ebm_right_shift};
 
+// Import the low mask builder that keeps rotated bits inside the subword
+use crate::bits::bit_manipulation::ebm_low_mask;
+
+/// Rotates the low `width` bits of a value left, wrapping within that subword
+///
+/// # Arguments
+/// * `a` - The operand whose low bits are rotated
+/// * `width` - The number of low bits forming the subword (1 to the type width)
+/// * `amount` - The number of positions to rotate left
+///
+/// # Returns
+/// * `T` - The rotated subword, with every bit above `width` cleared
+///
+/// # Implementation Details
+/// This function emulates a narrow rotate with the basic shift operations:
+/// 1. Masks the operand down to the low `width` bits
+/// 2. Reduces the rotation amount modulo `width`
+/// 3. Shifts the subword left and right by complementary amounts
+/// 4. Masks both halves so bits never leave the subword, even with sign-filling shifts
+/// 5. Combines the halves with `ebmor`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - A handful of shifts and masks, no loops
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_rotate::ebm_rotate_subword;
+/// let result = ebm_rotate_subword(0b1000000u8, 7, 1); // 0b0000001
+/// let result = ebm_rotate_subword(0b011u8, 3, 2); // 0b101
+/// let result = ebm_rotate_subword(0xF1u8, 4, 1); // 0b0010 (the high nibble is dropped)
+/// ```
+///
+/// # Function Logic
+/// The native rotate always uses the full width of the type. Ciphers and packed formats often
+/// rotate fields such as 7-bit or 12-bit values, so the wrap-around point has to be emulated.
+/// Bits above the subword are not part of the field and are cleared in the result.
+///
+/// # Safety Considerations
+/// - `width` must be between 1 and the type width (checked with a debug assertion)
+/// - Shift amounts always stay below the type width, so no shift can overflow
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_rotate_subword<T: EbmInt>(a: T, width: u32, amount: u32) -> T {
+    // The subword must be non-empty and fit inside the type
+    debug_assert!(width >= 1 && width <= T::BITS, "subword width out of range");
+
+    // Restrict the operand to the subword and the rotation to one full turn
+    let subword = ebm_and(a, ebm_low_mask::<T>(width));
+    let effective_rotate = amount % width;
+
+    // A rotation by zero leaves the subword as it is
+    if effective_rotate == 0 {
+        return subword;
+    }
+
+    // Move the low part up and the high part down, masking both to stay inside the subword
+    let left_part = ebm_and(ebm_left_shift(subword, effective_rotate), ebm_low_mask::<T>(width));
+    let right_part = ebm_and(ebm_right_shift(subword, width - effective_rotate), ebm_low_mask::<T>(effective_rotate));
+    ebmor(left_part, right_part)
+}
+
+// Repeats a lane-sized field in every `lane_bits`-wide lane of a 64-bit word
+fn repeat_lane(field: u64, lane_bits: u32) -> u64 {
+    (0..u64::BITS).step_by(lane_bits as usize).fold(0, |acc, offset| ebmor(acc, ebm_left_shift(field, offset)))
+}
+
+/// Rotates every `lane_bits`-wide lane of a 64-bit word left, independently of the other lanes
+///
+/// # Arguments
+/// * `a` - The word holding the packed lanes
+/// * `lane_bits` - The width of each lane, which must divide 64
+/// * `amount` - The number of positions every lane is rotated left
+///
+/// # Returns
+/// * `u64` - The word with each lane rotated within itself
+///
+/// # Implementation Details
+/// This function rotates all lanes at once with two shared masks (SWAR):
+/// 1. Reduces the rotation amount modulo `lane_bits`
+/// 2. Keeps the low `lane_bits - amount` bits of every lane and shifts them left by `amount`
+/// 3. Shifts the word right by `lane_bits - amount` and keeps the low `amount` bits of every
+///    lane, which are the bits that wrapped around
+/// 4. Combines both parts with `ebmor`; the masks stop bits from crossing into a neighbor lane
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Two shifts and two ANDs for the whole word, plus building the two lane masks
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_rotate::ebm_rotate_lanes;
+/// let result = ebm_rotate_lanes(0x8001_0000_0000_0080, 8, 1); // 0x0102_0000_0000_0001
+/// let result = ebm_rotate_lanes(0x1234_5678_9ABC_DEF0, 16, 4); // 0x2341_6785_ABC9_EF0D
+/// let result = ebm_rotate_lanes(0x1234_5678_9ABC_DEF0, 64, 4); // 0x2345_6789_ABCD_EF01
+/// ```
+///
+/// # Function Logic
+/// SIMD instruction sets rotate packed lanes in one instruction; this expresses the same
+/// operation inside a single general-purpose register. Each lane behaves exactly like
+/// `ebm_rotate_subword` applied to that lane alone. With `lane_bits = 64` it is the plain
+/// full-width rotation.
+///
+/// # Safety Considerations
+/// - `lane_bits` must be between 1 and 64 and divide 64 (checked with a debug assertion); other
+///   widths would leave a partial lane at the top
+/// - Shift amounts always stay below 64, so no shift can overflow
+/// - No undefined behavior possible
+pub fn ebm_rotate_lanes(a: u64, lane_bits: u32, amount: u32) -> u64 {
+    // The lanes have to tile the word exactly, and the divisors of 64 are its powers of two
+    debug_assert!(lane_bits <= u64::BITS && lane_bits.is_power_of_two(), "lane width must divide 64");
+
+    // A rotation by a whole turn leaves every lane as it is
+    let effective_rotate = amount % lane_bits;
+    if effective_rotate == 0 {
+        return a;
+    }
+
+    // Move the low part of each lane up and the wrapped-around top bits down
+    let stay_mask = repeat_lane(ebm_low_mask::<u64>(lane_bits - effective_rotate), lane_bits);
+    let wrap_mask = repeat_lane(ebm_low_mask::<u64>(effective_rotate), lane_bits);
+    let left_part = ebm_left_shift(ebm_and(a, stay_mask), effective_rotate);
+    let right_part = ebm_and(ebm_right_shift(a, lane_bits - effective_rotate), wrap_mask);
+    ebmor(left_part, right_part)
+}
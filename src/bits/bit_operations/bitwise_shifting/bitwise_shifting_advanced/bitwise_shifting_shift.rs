@@ -1,3 +1,360 @@
+// Bitwise Shifting Advanced Shift Operations for Eidolon Math Library
+// This module contains advanced shift operations built on top of the basic shifting functions
+// Every function here validates or clamps the shift amount instead of relying on operator masking
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
 
+// Import the integer trait that provides the bit width and saturation bounds
+use crate::bits::ebm_int::EbmInt;
 
+// Import the low mask used to make right shifts logical
+use crate::bits::bit_manipulation::ebm_low_mask;
 
+// Import the basic shift operations these advanced functions are built on
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::{ebm_and, ebmor};
+use crate::bits::bit_operations::bitwise_shifting::bitwise_shifting::{ebm_left_shift, ebm_right_shift};
+
+/// Performs a checked left shift that rejects shift amounts outside the type's bit width
+///
+/// # Arguments
+/// * `a` - The operand to be shifted left
+/// * `amount` - The number of positions to shift left
+///
+/// # Returns
+/// * `Option<T>` - `Some` with the shifted value, or `None` when `amount >= width`
+///
+/// # Implementation Details
+/// This function validates the shift amount before delegating to `ebm_left_shift`:
+/// 1. Reads the bit width of `T` from `T::BITS`
+/// 2. Returns `None` for any amount that meets or exceeds that width
+/// 3. Performs the shift with the basic left shift for all valid amounts
+/// 4. Never hits the debug-mode overflow panic or the release-mode masking
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Single comparison in front of the shift
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_shift::ebm_checked_shl;
+/// let result = ebm_checked_shl(1u8, 7); // Some(0x80)
+/// let result = ebm_checked_shl(1u8, 8); // None (8 >= 8 bits)
+/// let result = ebm_checked_shl(0x00FFu16, 8); // Some(0xFF00)
+/// ```
+///
+/// # Function Logic
+/// The built-in shift operator panics in debug builds and silently masks the amount in release
+/// builds when the amount is too large. This function makes the out-of-range case explicit so
+/// that user-supplied shift counts can be validated instead of producing surprising results.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in operators
+/// - No undefined behavior possible with valid numeric types
+/// - Out-of-range shift amounts are reported instead of masked
+/// - Compiler ensures type safety at compile time
+pub fn ebm_checked_shl<T: EbmInt>(a: T, amount: u32) -> Option<T> {
+    // Reject amounts that would overflow the shift instead of letting them be masked
+    if amount >= T::BITS {
+        return None;
+    }
+
+    Some(ebm_left_shift(a, amount))
+}
+
+/// Performs a checked right shift that rejects shift amounts outside the type's bit width
+///
+/// # Arguments
+/// * `a` - The operand to be shifted right
+/// * `amount` - The number of positions to shift right
+///
+/// # Returns
+/// * `Option<T>` - `Some` with the shifted value, or `None` when `amount >= width`
+///
+/// # Implementation Details
+/// This function validates the shift amount before delegating to `ebm_right_shift`:
+/// 1. Reads the bit width of `T` from `T::BITS`
+/// 2. Returns `None` for any amount that meets or exceeds that width
+/// 3. Performs the shift with the basic right shift for all valid amounts
+/// 4. Keeps the sign-filling behavior of signed types for valid amounts
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Single comparison in front of the shift
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_shift::ebm_checked_shr;
+/// let result = ebm_checked_shr(0x80u8, 7); // Some(1)
+/// let result = ebm_checked_shr(0x80u8, 8); // None (8 >= 8 bits)
+/// let result = ebm_checked_shr(-16i32, 2); // Some(-4) (sign bits are shifted in)
+/// ```
+///
+/// # Function Logic
+/// This is the right shift counterpart of `ebm_checked_shl`. Valid amounts behave exactly like
+/// the basic right shift, while amounts at or beyond the bit width are reported as `None`.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in operators
+/// - No undefined behavior possible with valid numeric types
+/// - Out-of-range shift amounts are reported instead of masked
+/// - Compiler ensures type safety at compile time
+pub fn ebm_checked_shr<T: EbmInt>(a: T, amount: u32) -> Option<T> {
+    // Reject amounts that would overflow the shift instead of letting them be masked
+    if amount >= T::BITS {
+        return None;
+    }
+
+    Some(ebm_right_shift(a, amount))
+}
+
+/// Performs a left shift that saturates to zero when the shift amount covers the whole type
+///
+/// # Arguments
+/// * `a` - The operand to be shifted left
+/// * `amount` - The number of positions to shift left
+///
+/// # Returns
+/// * `T` - The shifted value, or zero when `amount >= width`
+///
+/// # Implementation Details
+/// This function clamps the shift the way a barrel shifter with clamp behaves:
+/// 1. Attempts the shift through `ebm_checked_shl`
+/// 2. Falls back to `T::ZERO` when the amount is out of range
+/// 3. Matches the mathematical result of shifting every bit out of the value
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Single comparison in front of the shift
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_shift::ebm_shl_saturating;
+/// let result = ebm_shl_saturating(1u8, 7); // 0x80
+/// let result = ebm_shl_saturating(1u8, 8); // 0 (every bit shifted out)
+/// let result = ebm_shl_saturating(-1i32, 40); // 0
+/// ```
+///
+/// # Function Logic
+/// Shifting left by the full width or more moves every bit out of the value, so the only
+/// consistent result is zero. This removes the ambiguity between the debug-mode panic and the
+/// release-mode masking of the built-in operator, which is useful for fixed-point scaling.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in operators
+/// - No undefined behavior possible with valid numeric types
+/// - Out-of-range shift amounts produce a well-defined zero
+/// - Compiler ensures type safety at compile time
+pub fn ebm_shl_saturating<T: EbmInt>(a: T, amount: u32) -> T {
+    // Zero is the fully shifted-out result
+    ebm_checked_shl(a, amount).unwrap_or(T::ZERO)
+}
+
+/// Performs a right shift that saturates to the fill value when the shift amount covers the whole type
+///
+/// # Arguments
+/// * `a` - The operand to be shifted right
+/// * `amount` - The number of positions to shift right
+///
+/// # Returns
+/// * `T` - The shifted value, or the fill value when `amount >= width`
+///
+/// # Implementation Details
+/// This function clamps the shift the way a barrel shifter with clamp behaves:
+/// 1. Attempts the shift through `ebm_checked_shr`
+/// 2. Falls back to a shift by `width - 1` followed by a shift by 1 when the amount is out of range
+/// 3. That fallback yields 0 for unsigned types and for non-negative signed values
+/// 4. It yields -1 (all sign bits) for negative signed values
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Single comparison in front of the shift
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_shift::ebm_shr_saturating;
+/// let result = ebm_shr_saturating(0x80u8, 7); // 1
+/// let result = ebm_shr_saturating(0x80u8, 8); // 0 (every bit shifted out)
+/// let result = ebm_shr_saturating(-128i8, 20); // -1 (sign-filled)
+/// ```
+///
+/// # Function Logic
+/// Shifting right by the full width or more moves every value bit out, leaving only the bits
+/// that were shifted in. For unsigned types those are zeros; for signed types the arithmetic
+/// shift fills with copies of the sign bit. Shifting by `width - 1` and then by one more bit
+/// produces exactly that fill without ever using an out-of-range shift amount.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in operators
+/// - No undefined behavior possible with valid numeric types
+/// - Out-of-range shift amounts produce a well-defined fill value
+/// - Compiler ensures type safety at compile time
+pub fn ebm_shr_saturating<T: EbmInt>(a: T, amount: u32) -> T {
+    // Shifting by width - 1 and then by one more bit leaves only the fill bits
+    // This produces zeros for unsigned values and copies of the sign bit for signed values
+    ebm_checked_shr(a, amount).unwrap_or_else(|| ebm_right_shift(ebm_right_shift(a, T::BITS - 1), 1u32))
+}
+
+/// Performs a left shift that fails when any significant bit would be shifted out
+///
+/// # Arguments
+/// * `a` - The operand to be scaled by a power of two
+/// * `shift` - The exponent of the power of two to multiply by
+///
+/// # Returns
+/// * `Option<T>` - `Some(a * 2^shift)`, or `None` when the product does not fit in `T`
+///
+/// # Implementation Details
+/// This function verifies the shift by undoing it:
+/// 1. Handles amounts at or beyond the width: only zero survives such a shift
+/// 2. Shifts left with `ebm_left_shift`
+/// 3. Shifts the result back with `ebm_right_shift` and compares against the original
+/// 4. Any lost bit, or a changed sign for signed types, makes the comparison fail
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Two shifts and a comparison, no loops
+/// - Cache-friendly memory access patterns
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_shift::ebm_checked_shl_mul;
+/// let result = ebm_checked_shl_mul(0x10u8, 2); // Some(0x40)
+/// let result = ebm_checked_shl_mul(0x40u8, 2); // None (bit 6 would reach bit 8)
+/// let result = ebm_checked_shl_mul(0x40i8, 1); // None (the result would turn negative)
+/// ```
+///
+/// # Function Logic
+/// `ebm_checked_shl` only validates the shift amount, while this function validates the value:
+/// the shift must be an exact multiplication by `2^shift`. This catches silent data loss when
+/// scaling values. For signed types the arithmetic right shift restores the sign, so a result
+/// whose sign bit was overwritten is rejected as well.
+///
+/// # Safety Considerations
+/// - Uses Rust's safe built-in operators
+/// - Never shifts by the full width or more
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_checked_shl_mul<T: EbmInt>(a: T, shift: u32) -> Option<T> {
+    // Shifting by the whole width only keeps zero intact
+    if shift >= T::BITS {
+        return if a == T::ZERO { Some(a) } else { None };
+    }
+
+    // The shift is exact only if shifting back restores the original value
+    let shifted = ebm_left_shift(a, shift);
+    if ebm_right_shift(shifted, shift) == a {
+        Some(shifted)
+    } else {
+        None
+    }
+}
+
+/// Performs a left shift that saturates to the signed range instead of overflowing
+///
+/// # Arguments
+/// * `a` - The fixed-point value to scale by a power of two
+/// * `shift` - The exponent of the power of two to multiply by
+///
+/// # Returns
+/// * `T` - `a * 2^shift`, clamped to `T::MIN` or `T::MAX` when it does not fit
+///
+/// # Implementation Details
+/// This function builds on the exactness check of `ebm_checked_shl_mul`:
+/// 1. Returns the shifted value when the shift is an exact multiplication
+/// 2. Otherwise clamps to `T::MIN` for negative values
+/// 3. And to `T::MAX` for positive values
+/// 4. Zero stays zero for every shift amount
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Two shifts, a comparison and a sign test, no loops
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_shift::ebm_signed_shl_saturating;
+/// let result = ebm_signed_shl_saturating(0x10i8, 2); // 0x40
+/// let result = ebm_signed_shl_saturating(0x20i8, 2); // 127 (saturated at i8::MAX)
+/// let result = ebm_signed_shl_saturating(-0x20i8, 3); // -128 (saturated at i8::MIN)
+/// ```
+///
+/// # Function Logic
+/// DSP code scales fixed-point samples by powers of two and expects clipping rather than
+/// wrap-around, which would flip the sign of a loud sample. Overflow is detected whenever
+/// shifting back does not restore the input, and the sign of the input picks the bound. For
+/// unsigned types the function saturates at `T::MAX`.
+///
+/// # Safety Considerations
+/// - Never shifts by the full width or more
+/// - No undefined behavior possible with valid numeric types
+/// - Out-of-range shift amounts saturate like any other overflow
+pub fn ebm_signed_shl_saturating<T: EbmInt>(a: T, shift: u32) -> T {
+    // Keep exact results, otherwise clip toward the sign of the input
+    match ebm_checked_shl_mul(a, shift) {
+        Some(scaled) => scaled,
+        None if a < T::ZERO => T::MIN,
+        None => T::MAX,
+    }
+}
+
+/// Shifts the concatenation of two values left and returns the upper half
+///
+/// # Arguments
+/// * `hi` - The upper half of the double-width value
+/// * `lo` - The lower half of the double-width value
+/// * `amount` - The number of positions to shift left, below the bit width
+///
+/// # Returns
+/// * `T` - The upper half of `(hi:lo) << amount`
+///
+/// # Implementation Details
+/// This function emulates the funnel shift (SHLD) instruction:
+/// 1. Returns `hi` unchanged for a shift of zero
+/// 2. Shifts `hi` left by `amount` with `ebm_left_shift`
+/// 3. Shifts `lo` right by `width - amount` so its top bits fill the vacated low bits
+/// 4. Masks those bits with `ebm_low_mask` so the shift is logical for signed types too
+/// 5. Combines both parts with `ebmor`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Two shifts, a mask and an OR (a single SHLD instruction on x86)
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_shift::ebm_funnel_shl;
+/// let result = ebm_funnel_shl(0x12u8, 0x34u8, 4); // 0x23
+/// let result = ebm_funnel_shl(0x00u8, 0x80u8, 1); // 0x01 (the top bit of lo moves into hi)
+/// let result = ebm_funnel_shl(0xABu8, 0xCDu8, 0); // 0xAB
+/// ```
+///
+/// # Function Logic
+/// Shifting a number stored in several words moves the top bits of every word into the word
+/// above it. The funnel shift computes one output word from two neighboring input words, which
+/// is the inner step of multi-word shifts. A shift of zero is handled separately because the
+/// complementary shift by the full width is not allowed.
+///
+/// # Safety Considerations
+/// - `amount` must be below the bit width (checked with a debug assertion)
+/// - Never shifts by the full width or more
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_funnel_shl<T: EbmInt>(hi: T, lo: T, amount: u32) -> T {
+    // The shift has to stay inside the upper half
+    debug_assert!(amount < T::BITS, "funnel shift amount out of range");
+
+    // A zero shift would need a complementary shift by the full width
+    if amount == 0 {
+        return hi;
+    }
+
+    // Fill the vacated low bits of hi with the top bits of lo
+    let carried = ebm_and(ebm_right_shift(lo, T::BITS - amount), ebm_low_mask::<T>(amount));
+    ebmor(ebm_left_shift(hi, amount), carried)
+}
@@ -6,6 +6,8 @@
 // It will import and organize all the bitwise shifting functionality
 
 // Import the basic bitwise shifting operations
+// The basic file shares the group name by design (see README file organization)
+#[allow(clippy::module_inception)]
 pub mod bitwise_shifting;
 
 // Import the advanced bitwise shifting operations
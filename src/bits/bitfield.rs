@@ -0,0 +1,100 @@
+// Bit Field Structures for Eidolon Math Library
+// This module contains the define_bitfield! macro for packed register-style structs
+// The generated accessors are built on the extract and insert primitives from bit_manipulation
+// Backing types can be any of: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+
+/// Defines a packed bit-field struct with a getter and a setter for every named field
+///
+/// # Syntax
+/// ```text
+/// define_bitfield! {
+///     pub struct Name(BackingType) {
+///         getter_name, setter_name: offset, width;
+///         ...
+///     }
+/// }
+/// ```
+///
+/// # Implementation Details
+/// The macro generates a tuple struct wrapping the backing integer:
+/// 1. `from_raw` and `raw` convert between the struct and the backing integer
+/// 2. Every getter returns its field with `ebm_extract_bits`, moved down to bit 0
+/// 3. Every setter writes its field with `ebm_insert_bits`, leaving all other bits untouched
+/// 4. The struct derives `Debug`, `Clone`, `Copy`, `Default`, `PartialEq` and `Eq`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - The struct has exactly the size of its backing type
+/// - Every accessor is a shift and a mask after inlining
+///
+/// # Examples
+/// ```
+/// use eidolon_math::define_bitfield;
+/// define_bitfield! {
+///     pub struct Status(u8) {
+///         mode, set_mode: 0, 3;
+///         enabled, set_enabled: 3, 1;
+///     }
+/// }
+/// let mut status = Status::default();
+/// status.set_mode(5);
+/// status.set_enabled(1);
+/// let mode = status.mode(); // 5
+/// let raw = status.raw(); // 0b0000_1101
+/// ```
+///
+/// # Function Logic
+/// Hardware registers pack many small fields into one word. Writing the shift-and-mask code for
+/// each field by hand is repetitive and error-prone, so the macro generates it from the layout.
+///
+/// # Safety Considerations
+/// - Fields must lie inside the backing type (checked with debug assertions on access)
+/// - Setter values wider than the field are truncated to the field width
+/// - No undefined behavior possible with valid numeric types
+#[macro_export]
+macro_rules! define_bitfield {
+    (
+        $(#[$struct_meta:meta])*
+        $vis:vis struct $name:ident($backing:ty) {
+            $(
+                $(#[$field_meta:meta])*
+                $getter:ident, $setter:ident : $offset:expr, $width:expr;
+            )*
+        }
+    ) => {
+        $(#[$struct_meta])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+        $vis struct $name($backing);
+
+        impl $name {
+            /// Wraps a raw backing value without changing any bits
+            #[allow(dead_code)]
+            pub const fn from_raw(raw: $backing) -> Self {
+                Self(raw)
+            }
+
+            /// Returns the raw backing value with every field packed in place
+            #[allow(dead_code)]
+            pub const fn raw(self) -> $backing {
+                self.0
+            }
+
+            $(
+                $(#[$field_meta])*
+                #[allow(dead_code)]
+                pub fn $getter(&self) -> $backing {
+                    $crate::bits::bit_manipulation::ebm_extract_bits(self.0, $offset, $width)
+                }
+
+                $(#[$field_meta])*
+                #[allow(dead_code)]
+                pub fn $setter(&mut self, value: $backing) {
+                    self.0 = $crate::bits::bit_manipulation::ebm_insert_bits(self.0, value, $offset, $width);
+                }
+            )*
+        }
+    };
+}
+
+// Make the macro reachable through this module as well as the crate root
+pub use crate::define_bitfield;
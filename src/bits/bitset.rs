@@ -0,0 +1,153 @@
+// Bit Set Structures for Eidolon Math Library
+// This module contains containers that store boolean flags one per bit
+// They are built on the set, clear and test bit primitives from the bitwise logic module
+// Packing flags into 64-bit words uses an eighth of the memory of a Vec<bool>
+
+// Import the single-bit primitives the containers are built on
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_and::{ebm_clear_bit, ebm_test_bit};
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_or::ebm_set_bit;
+
+// The number of flags stored in every backing word
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A growable sequence of booleans stored one per bit
+///
+/// # Implementation Details
+/// The flags are packed into a `Vec<u64>`:
+/// 1. Flag `i` lives in word `i / 64` at bit position `i % 64`
+/// 2. A new word is appended whenever a push crosses a word boundary
+/// 3. Unused bits in the last word are always kept at 0
+/// 4. Every access goes through `ebm_set_bit`, `ebm_clear_bit` and `ebm_test_bit`
+///
+/// # Performance Characteristics
+/// - One bit of heap memory per flag, plus the vector's spare capacity
+/// - Constant-time push, get and set
+/// - Iteration walks the words sequentially
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::bitset::PackedBools;
+/// let mut flags = PackedBools::new();
+/// flags.push(true);
+/// flags.push(false);
+/// let first = flags.get(0); // Some(true)
+/// let missing = flags.get(2); // None
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackedBools {
+    // The backing words holding 64 flags each
+    words: Vec<u64>,
+    // The number of flags stored, which may leave the last word partially used
+    len: usize,
+}
+
+impl PackedBools {
+    /// Creates an empty sequence without allocating
+    ///
+    /// # Returns
+    /// * `PackedBools` - A sequence with no flags
+    pub fn new() -> Self {
+        Self {
+            words: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Creates an empty sequence with room for at least `capacity` flags
+    ///
+    /// # Arguments
+    /// * `capacity` - The number of flags to reserve memory for
+    ///
+    /// # Returns
+    /// * `PackedBools` - A sequence with no flags
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            words: Vec::with_capacity(capacity.div_ceil(WORD_BITS)),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of flags stored
+    ///
+    /// # Returns
+    /// * `usize` - The number of flags pushed so far
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no flags are stored
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the sequence is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends a flag to the end of the sequence
+    ///
+    /// # Arguments
+    /// * `value` - The flag to append
+    ///
+    /// # Function Logic
+    /// A new zeroed word is appended when the previous word is full, so only set flags
+    /// need a write into the backing word.
+    pub fn push(&mut self, value: bool) {
+        // Start a new word when the current one is full
+        if self.len == self.words.len() * WORD_BITS {
+            self.words.push(0);
+        }
+
+        // New words are zeroed, so only true flags need to be written
+        if value {
+            let word = self.len / WORD_BITS;
+            self.words[word] = ebm_set_bit(self.words[word], (self.len % WORD_BITS) as u32);
+        }
+
+        self.len += 1;
+    }
+
+    /// Reads the flag at the given position
+    ///
+    /// # Arguments
+    /// * `index` - The position of the flag to read
+    ///
+    /// # Returns
+    /// * `Option<bool>` - The flag, or `None` when `index` is out of bounds
+    pub fn get(&self, index: usize) -> Option<bool> {
+        // Reject positions that were never pushed
+        if index >= self.len {
+            return None;
+        }
+
+        Some(ebm_test_bit(self.words[index / WORD_BITS], (index % WORD_BITS) as u32))
+    }
+
+    /// Overwrites the flag at the given position
+    ///
+    /// # Arguments
+    /// * `index` - The position of the flag to write
+    /// * `value` - The new flag value
+    ///
+    /// # Panics
+    /// Panics when `index` is out of bounds, like indexing a `Vec<bool>`
+    pub fn set(&mut self, index: usize, value: bool) {
+        // Only positions that were pushed can be overwritten
+        assert!(index < self.len, "index {} out of bounds for length {}", index, self.len);
+
+        let word = index / WORD_BITS;
+        let bit = (index % WORD_BITS) as u32;
+        self.words[word] = if value {
+            ebm_set_bit(self.words[word], bit)
+        } else {
+            ebm_clear_bit(self.words[word], bit)
+        };
+    }
+
+    /// Iterates over the flags from the first to the last
+    ///
+    /// # Returns
+    /// * `impl Iterator<Item = bool>` - An iterator yielding every stored flag in order
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(move |index| ebm_test_bit(self.words[index / WORD_BITS], (index % WORD_BITS) as u32))
+    }
+}
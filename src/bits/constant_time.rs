@@ -0,0 +1,117 @@
+// Constant-Time Operations for Eidolon Math Library
+// This module contains functions whose running time does not depend on secret data
+// They replace data-dependent branches and early exits with masks and accumulators
+// Built on the bit operations from the bit_operations module wherever possible
+
+// Import necessary standard library components for low-level operations
+use std::hint::black_box;
+
+// Import the basic operations the constant-time functions are built on
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::{ebm_and, ebmor, ebmxor};
+
+/// Compares two byte slices for equality in constant time
+///
+/// # Arguments
+/// * `a` - The first byte slice, e.g. a received MAC tag
+/// * `b` - The second byte slice, e.g. the expected MAC tag
+///
+/// # Returns
+/// * `bool` - `true` if both slices have the same length and contents
+///
+/// # Implementation Details
+/// This function accumulates all differences before deciding:
+/// 1. Returns `false` for slices of different lengths, since the length is not secret
+/// 2. XORs every byte pair with `ebmxor`, which is zero exactly for equal bytes
+/// 3. ORs every difference into an accumulator with `ebmor`, without ever exiting early
+/// 4. Passes the accumulator through `black_box` so the compiler cannot add an early exit
+/// 5. Reports equality when the accumulator is still zero
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Always visits every byte, even after the first difference
+/// - Linear time in the length of the slices
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::constant_time::ebm_ct_eq;
+/// let result = ebm_ct_eq(b"tag-1234", b"tag-1234"); // true
+/// let result = ebm_ct_eq(b"tag-1234", b"tag-1235"); // false
+/// let result = ebm_ct_eq(b"tag", b"tag-1234"); // false (different lengths)
+/// ```
+///
+/// # Function Logic
+/// A comparison that stops at the first differing byte takes longer the more leading bytes
+/// match, which lets an attacker guess a MAC tag one byte at a time by measuring the response
+/// time. Accumulating every difference makes the time depend only on the length.
+///
+/// # Safety Considerations
+/// - Both slices are expected to have the same length; different lengths are rejected
+///   immediately, so the length itself must not be secret
+/// - The final comparison happens once, after all bytes have been processed
+/// - No undefined behavior possible
+pub fn ebm_ct_eq(a: &[u8], b: &[u8]) -> bool {
+    // The length is public, so a mismatch may be reported right away
+    if a.len() != b.len() {
+        return false;
+    }
+
+    // Collect every differing bit without branching on the data
+    let mut difference = 0u8;
+    for (&left, &right) in a.iter().zip(b) {
+        difference = black_box(ebmor(difference, ebmxor(left, right)));
+    }
+
+    difference == 0
+}
+
+/// Copies one byte slice into another if a condition holds, without branching on it
+///
+/// # Arguments
+/// * `dst` - The destination, overwritten with `src` when `choice` is true
+/// * `src` - The source bytes
+/// * `choice` - Whether the copy takes effect
+///
+/// # Returns
+/// * `()` - `dst` is updated in place
+///
+/// # Implementation Details
+/// This function blends the slices with a byte mask instead of branching:
+/// 1. Checks that both slices have the same length
+/// 2. Turns `choice` into a mask of `0xFF` or `0x00` by negating it as a byte
+/// 3. Passes the mask through `black_box` so the compiler cannot turn it back into a branch
+/// 4. Replaces every destination byte with `dst ^ ((dst ^ src) & mask)`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Always reads and writes every byte, whatever the choice
+/// - Linear time in the length of the slices
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::constant_time::ebm_ct_copy;
+/// let mut dst = [0u8; 4];
+/// ebm_ct_copy(&mut dst, &[1, 2, 3, 4], true); // dst == [1, 2, 3, 4]
+/// ebm_ct_copy(&mut dst, &[9, 9, 9, 9], false); // dst is unchanged
+/// ```
+///
+/// # Function Logic
+/// With a mask of all ones the blend yields `src`, with a mask of zero it yields `dst`. Looping
+/// over every table entry and copying only the wanted one this way performs a table lookup
+/// whose memory accesses and timing do not reveal the secret index.
+///
+/// # Safety Considerations
+/// - Panics when the slices have different lengths
+/// - The choice only ever feeds the mask, never a branch
+/// - No undefined behavior possible
+pub fn ebm_ct_copy(dst: &mut [u8], src: &[u8], choice: bool) {
+    // Both buffers must describe the same bytes
+    assert_eq!(dst.len(), src.len(), "slices to copy between must have equal length");
+
+    // 0xFF selects the source, 0x00 keeps the destination
+    let mask = black_box((choice as u8).wrapping_neg());
+
+    // Blend every byte through the mask
+    for (target, &source) in dst.iter_mut().zip(src) {
+        *target = ebmxor(*target, ebm_and(ebmxor(*target, source), mask));
+    }
+}
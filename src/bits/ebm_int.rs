@@ -0,0 +1,413 @@
+// Eidolon Integer Trait for Eidolon Math Library
+// This module defines the EbmInt trait that describes every primitive integer type
+// It gives generic functions access to constants and intrinsics that std::ops cannot express
+// Implemented for all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+
+// Import necessary standard library components for low-level operations
+use std::fmt::Debug;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Rem, Shl, Shr, Sub};
+
+/// Describes a primitive integer type usable by the generic Eidolon functions
+///
+/// # Implementation Details
+/// The trait bundles everything generic bit-level code needs from an integer type:
+/// 1. All bitwise, shift and arithmetic operators through the `std::ops` supertraits
+/// 2. The bit width and the constants 0, 1, MIN and MAX
+/// 3. Whether the type is signed
+/// 4. The hardware-accelerated counting and wrapping intrinsics of the primitive types
+/// 5. Truncating conversions from and to `u32` for building masks and counters
+/// 6. Construction from little- and big-endian byte slices for parsing binary data
+///
+/// # Performance Characteristics
+/// - Every method forwards to the inherent method of the primitive type
+/// - Fully inlined and monomorphized, so there is no runtime cost over direct use
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::ebm_int::EbmInt;
+/// fn width<T: EbmInt>(_a: T) -> u32 { T::BITS }
+/// let result = width(0u16); // 16
+/// let result = 0xF0u8.count_ones(); // 4
+/// ```
+///
+/// # Safety Considerations
+/// - Only implemented for the twelve primitive integer types
+/// - Conversions are explicit truncations, identical to `as` casts
+/// - The byte slice constructors panic when the slice length differs from the type size
+pub trait EbmInt:
+    Copy
+    + Debug
+    + Default
+    + Eq
+    + Ord
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    /// The number of bits in the type
+    const BITS: u32;
+
+    /// The value 0
+    const ZERO: Self;
+
+    /// The value 1
+    const ONE: Self;
+
+    /// The smallest representable value
+    const MIN: Self;
+
+    /// The largest representable value
+    const MAX: Self;
+
+    /// Whether the type is a signed two's-complement integer
+    const SIGNED: bool;
+
+    /// Returns the number of set bits
+    fn count_ones(self) -> u32;
+
+    /// Returns the number of leading zero bits
+    fn leading_zeros(self) -> u32;
+
+    /// Returns the number of trailing zero bits
+    fn trailing_zeros(self) -> u32;
+
+    /// Reverses the order of all bits
+    fn reverse_bits(self) -> Self;
+
+    /// Rotates the bits left, with the amount taken modulo the bit width
+    fn rotate_left(self, n: u32) -> Self;
+
+    /// Rotates the bits right, with the amount taken modulo the bit width
+    fn rotate_right(self, n: u32) -> Self;
+
+    /// Adds two values, wrapping around at the type boundary
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Subtracts two values, wrapping around at the type boundary
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Multiplies two values, wrapping around at the type boundary
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    /// Negates the value in two's complement, wrapping around at the type boundary
+    fn wrapping_neg(self) -> Self;
+
+    /// Adds two values, returning `None` on overflow
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Subtracts two values, returning `None` on overflow
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Multiplies two values, returning `None` on overflow
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Negates the value, returning `None` when the result is not representable
+    fn checked_neg(self) -> Option<Self>;
+
+    /// Converts a `u32` into this type, truncating like an `as` cast
+    fn from_u32(value: u32) -> Self;
+
+    /// Converts this value into a `u32`, truncating like an `as` cast
+    fn to_u32(self) -> u32;
+
+    /// Builds a value from its little-endian bytes; `bytes` must hold exactly `BITS / 8` bytes
+    fn from_le_slice(bytes: &[u8]) -> Self;
+
+    /// Builds a value from its big-endian bytes; `bytes` must hold exactly `BITS / 8` bytes
+    fn from_be_slice(bytes: &[u8]) -> Self;
+}
+
+// Implement the trait for every primitive integer type by forwarding to the inherent methods
+macro_rules! impl_ebm_int {
+    ($($t:ty => $signed:expr),* $(,)?) => {
+        $(
+            impl EbmInt for $t {
+                const BITS: u32 = <$t>::BITS;
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+                const SIGNED: bool = $signed;
+
+                #[inline]
+                fn count_ones(self) -> u32 {
+                    <$t>::count_ones(self)
+                }
+
+                #[inline]
+                fn leading_zeros(self) -> u32 {
+                    <$t>::leading_zeros(self)
+                }
+
+                #[inline]
+                fn trailing_zeros(self) -> u32 {
+                    <$t>::trailing_zeros(self)
+                }
+
+                #[inline]
+                fn reverse_bits(self) -> Self {
+                    <$t>::reverse_bits(self)
+                }
+
+                #[inline]
+                fn rotate_left(self, n: u32) -> Self {
+                    <$t>::rotate_left(self, n)
+                }
+
+                #[inline]
+                fn rotate_right(self, n: u32) -> Self {
+                    <$t>::rotate_right(self, n)
+                }
+
+                #[inline]
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$t>::wrapping_add(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    <$t>::wrapping_sub(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_mul(self, rhs: Self) -> Self {
+                    <$t>::wrapping_mul(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_neg(self) -> Self {
+                    <$t>::wrapping_neg(self)
+                }
+
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                #[inline]
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                #[inline]
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                #[inline]
+                fn checked_neg(self) -> Option<Self> {
+                    <$t>::checked_neg(self)
+                }
+
+                #[inline]
+                fn from_u32(value: u32) -> Self {
+                    value as $t
+                }
+
+                #[inline]
+                fn to_u32(self) -> u32 {
+                    self as u32
+                }
+
+                #[inline]
+                fn from_le_slice(bytes: &[u8]) -> Self {
+                    let mut array = [0u8; std::mem::size_of::<$t>()];
+                    array.copy_from_slice(bytes);
+                    <$t>::from_le_bytes(array)
+                }
+
+                #[inline]
+                fn from_be_slice(bytes: &[u8]) -> Self {
+                    let mut array = [0u8; std::mem::size_of::<$t>()];
+                    array.copy_from_slice(bytes);
+                    <$t>::from_be_bytes(array)
+                }
+            }
+        )*
+    };
+}
+
+impl_ebm_int! {
+    u8 => false,
+    u16 => false,
+    u32 => false,
+    u64 => false,
+    u128 => false,
+    usize => false,
+    i8 => true,
+    i16 => true,
+    i32 => true,
+    i64 => true,
+    i128 => true,
+    isize => true,
+}
+
+/// Describes a signed primitive integer type and its unsigned counterpart of the same width
+///
+/// # Implementation Details
+/// The pairing lets generic code move between the signed and unsigned views of a bit pattern:
+/// 1. `Unsigned` names the unsigned type of the same width
+/// 2. `reinterpret_unsigned` keeps every bit and only changes the type, like an `as` cast
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::ebm_int::EbmSigned;
+/// let result = (-1i8).reinterpret_unsigned(); // 255u8
+/// ```
+///
+/// # Safety Considerations
+/// - Only implemented for the six signed primitive integer types
+/// - The conversion never changes the bit pattern
+pub trait EbmSigned: EbmInt {
+    /// The unsigned type of the same width
+    type Unsigned: EbmUnsigned<Signed = Self>;
+
+    /// Reinterprets the bits as the unsigned type
+    fn reinterpret_unsigned(self) -> Self::Unsigned;
+}
+
+/// Describes an unsigned primitive integer type and its signed counterpart of the same width
+///
+/// # Implementation Details
+/// This is the reverse direction of `EbmSigned`:
+/// 1. `Signed` names the signed type of the same width
+/// 2. `reinterpret_signed` keeps every bit and only changes the type, like an `as` cast
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::ebm_int::EbmUnsigned;
+/// let result = 255u8.reinterpret_signed(); // -1i8
+/// ```
+///
+/// # Safety Considerations
+/// - Only implemented for the six unsigned primitive integer types
+/// - The conversion never changes the bit pattern
+pub trait EbmUnsigned: EbmInt {
+    /// The signed type of the same width
+    type Signed: EbmSigned<Unsigned = Self>;
+
+    /// Reinterprets the bits as the signed type
+    fn reinterpret_signed(self) -> Self::Signed;
+}
+
+// Pair every signed type with the unsigned type of the same width
+macro_rules! impl_ebm_sign_pair {
+    ($($s:ty => $u:ty),* $(,)?) => {
+        $(
+            impl EbmSigned for $s {
+                type Unsigned = $u;
+
+                #[inline]
+                fn reinterpret_unsigned(self) -> $u {
+                    self as $u
+                }
+            }
+
+            impl EbmUnsigned for $u {
+                type Signed = $s;
+
+                #[inline]
+                fn reinterpret_signed(self) -> $s {
+                    self as $s
+                }
+            }
+        )*
+    };
+}
+
+impl_ebm_sign_pair! {
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+}
+
+/// Describes an unsigned primitive integer type and the unsigned type of twice its width
+///
+/// # Implementation Details
+/// The pairing lets generic code produce results that need twice the bits of their inputs:
+/// 1. `Wide` names the unsigned type of double width
+/// 2. `widen` zero-extends the value, like an `as` cast to the wider type
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::ebm_int::EbmWiden;
+/// let result = 0xFFu8.widen(); // 0x00FFu16
+/// ```
+///
+/// # Safety Considerations
+/// - Only implemented for `u8`, `u16`, `u32` and `u64`, the types with a double-width partner
+/// - The conversion never loses bits
+pub trait EbmWiden: EbmUnsigned {
+    /// The unsigned type of twice the width
+    type Wide: EbmNarrow<Narrow = Self>;
+
+    /// Zero-extends the value to the wide type
+    fn widen(self) -> Self::Wide;
+}
+
+/// Describes an unsigned primitive integer type and the unsigned type of half its width
+///
+/// # Implementation Details
+/// This is the reverse direction of `EbmWiden`:
+/// 1. `Narrow` names the unsigned type of half the width
+/// 2. `narrow` keeps the low half of the bits, like an `as` cast to the narrower type
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::ebm_int::EbmNarrow;
+/// let result = 0x1234u16.narrow(); // 0x34u8
+/// ```
+///
+/// # Safety Considerations
+/// - Only implemented for `u16`, `u32`, `u64` and `u128`, the types with a half-width partner
+/// - The high half of the bits is discarded
+pub trait EbmNarrow: EbmUnsigned {
+    /// The unsigned type of half the width
+    type Narrow: EbmWiden<Wide = Self>;
+
+    /// Truncates the value to the narrow type
+    fn narrow(self) -> Self::Narrow;
+}
+
+// Pair every unsigned type with the unsigned type of twice its width
+macro_rules! impl_ebm_width_pair {
+    ($($n:ty => $w:ty),* $(,)?) => {
+        $(
+            impl EbmWiden for $n {
+                type Wide = $w;
+
+                #[inline]
+                fn widen(self) -> $w {
+                    <$w>::from(self)
+                }
+            }
+
+            impl EbmNarrow for $w {
+                type Narrow = $n;
+
+                #[inline]
+                fn narrow(self) -> $n {
+                    self as $n
+                }
+            }
+        )*
+    };
+}
+
+impl_ebm_width_pair! {
+    u8 => u16,
+    u16 => u32,
+    u32 => u64,
+    u64 => u128,
+}
@@ -0,0 +1,226 @@
+// Error-Correcting Codes for Eidolon Math Library
+// This module contains encoders and decoders for small error-correcting and error-detecting codes
+// The Hamming(7,4) code stores four data bits with three parity bits and fixes any single-bit error
+// Built on the bit operations from the bit_operations module wherever possible
+
+// Import the deposit used to place the data bits into the codeword
+use crate::bits::bit_manipulation::ebm_pdep;
+
+// Import the single-bit primitives used for parity bits and error correction
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_and::ebm_test_bit;
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_or::ebm_set_bit;
+
+// Import the basic operations used to evaluate the parity checks and flip the erroneous bit
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::{ebm_and, ebmxor};
+
+// Import the parity shared by the Hamming checks and the parity framing
+use crate::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_parity;
+
+// Bit positions of the data bits d1..d4 in a codeword (codeword positions 3, 5, 6 and 7)
+const HAMMING74_DATA_MASK: u8 = 0b0111_0100;
+
+// Bits covered by the parity checks p1, p2 and p4, in syndrome bit order
+const HAMMING74_CHECK_MASKS: [u8; 3] = [0b0101_0101, 0b0110_0110, 0b0111_1000];
+
+/// Encodes a 4-bit value as a 7-bit Hamming(7,4) codeword
+///
+/// # Arguments
+/// * `nibble` - The data to encode, taken from its low four bits
+///
+/// # Returns
+/// * `u8` - The codeword in bits 0 to 6, with bit 7 clear
+///
+/// # Implementation Details
+/// This function uses the classic layout with codeword positions 1 to 7 stored in bits 0 to 6:
+/// 1. Deposits the data bits d1..d4 into positions 3, 5, 6 and 7 with `ebm_pdep`
+/// 2. Computes parity bit p1 over positions 1, 3, 5, 7, p2 over 2, 3, 6, 7 and p4 over 4, 5, 6, 7
+/// 3. Sets each parity bit so that every check covers an even number of ones
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One deposit and three masked population counts
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::ecc::ebm_hamming74_encode;
+/// let result = ebm_hamming74_encode(0b0000); // 0b000_0000
+/// let result = ebm_hamming74_encode(0b1011); // 0b101_0101
+/// let result = ebm_hamming74_encode(0b1111); // 0b111_1111
+/// ```
+///
+/// # Function Logic
+/// Parity bit `p(2^k)` sits at the codeword position `2^k` and covers every position whose
+/// index has bit `k` set. A flipped bit therefore fails exactly the checks that spell out its
+/// position in binary, which is what lets the decoder locate and repair it.
+///
+/// # Safety Considerations
+/// - Bits 4 to 7 of the input are ignored
+/// - No undefined behavior possible
+pub fn ebm_hamming74_encode(nibble: u8) -> u8 {
+    // Place the data bits around the parity positions
+    let mut code = ebm_pdep(nibble, HAMMING74_DATA_MASK);
+
+    // Parity bit k lives at codeword position 2^k, which is bit 2^k - 1
+    for (k, &check) in HAMMING74_CHECK_MASKS.iter().enumerate() {
+        if ebm_parity(ebm_and(code, check)) {
+            code = ebm_set_bit(code, (1 << k) - 1);
+        }
+    }
+    code
+}
+
+/// Decodes a 7-bit Hamming(7,4) codeword, correcting a single-bit error
+///
+/// # Arguments
+/// * `code` - The received codeword in bits 0 to 6
+///
+/// # Returns
+/// * `(u8, bool)` - The recovered 4-bit value, and whether a bit error was corrected
+///
+/// # Implementation Details
+/// This function computes the syndrome and repairs the codeword before extracting the data:
+/// 1. Recomputes the three parity checks over the received bits
+/// 2. Combines the failed checks into the syndrome, which is the position of the flipped bit
+/// 3. Flips that bit back with `ebmxor` when the syndrome is non-zero
+/// 4. Collects the data bits from positions 3, 5, 6 and 7 into the low nibble
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Three masked population counts and four bit tests
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::ecc::ebm_hamming74_decode;
+/// let result = ebm_hamming74_decode(0b101_0101); // (0b1011, false)
+/// let result = ebm_hamming74_decode(0b101_0111); // (0b1011, true) (bit 1 was flipped)
+/// ```
+///
+/// # Function Logic
+/// A codeword without errors passes every check, so the syndrome is zero. A single flipped bit
+/// fails the checks matching the binary digits of its position, so the syndrome names it
+/// directly. Two or more flipped bits cannot be told apart from a different single-bit error and
+/// decode to the wrong value; detecting them needs an extra overall parity bit.
+///
+/// # Safety Considerations
+/// - Bit 7 of the input is ignored
+/// - The syndrome is always a valid position from 1 to 7
+/// - No undefined behavior possible
+pub fn ebm_hamming74_decode(code: u8) -> (u8, bool) {
+    // Every failed check contributes its bit of the error position
+    let syndrome = HAMMING74_CHECK_MASKS
+        .iter()
+        .enumerate()
+        .filter(|&(_, &check)| ebm_parity(ebm_and(code, check)))
+        .fold(0u32, |acc, (k, _)| acc | (1 << k));
+
+    // Repair the bit at the named position
+    let corrected = syndrome != 0;
+    let repaired = if corrected { ebmxor(code, 1 << (syndrome - 1)) } else { code };
+
+    // Gather the data bits d1..d4 back into the low nibble
+    let nibble = [2u32, 4, 5, 6]
+        .iter()
+        .enumerate()
+        .filter(|&(_, &position)| ebm_test_bit(repaired, position))
+        .fold(0u8, |acc, (index, _)| ebm_set_bit(acc, index as u32));
+    (nibble, corrected)
+}
+
+// Bit position of the parity bit in a framed byte
+const PARITY_BIT: u32 = 8;
+
+/// Frames a byte with an even or odd parity bit in bit 8
+///
+/// # Arguments
+/// * `byte` - The data byte to frame
+/// * `even` - `true` for even parity, `false` for odd parity
+///
+/// # Returns
+/// * `u16` - The byte in bits 0 to 7 and its parity bit in bit 8
+///
+/// # Implementation Details
+/// This function derives the parity bit from the population count:
+/// 1. Computes the parity of the data bits with `ebm_parity`
+/// 2. Sets the parity bit when the data alone would give the wrong total parity
+/// 3. Places the parity bit above the data with `ebm_set_bit`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One parity computation and one bit set
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::ecc::ebm_append_parity;
+/// let result = ebm_append_parity(0b0000_0011, true); // 0b0_0000_0011 (already even)
+/// let result = ebm_append_parity(0b0000_0111, true); // 0b1_0000_0111
+/// let result = ebm_append_parity(0b0000_0011, false); // 0b1_0000_0011
+/// ```
+///
+/// # Function Logic
+/// With even parity the nine framed bits always contain an even number of ones, with odd
+/// parity an odd number, as configured on a UART. Any single flipped bit changes that count by
+/// one and is detected by `ebm_check_parity`, although it cannot be located.
+///
+/// # Safety Considerations
+/// - Bits 9 to 15 of the result are always clear
+/// - No undefined behavior possible
+pub fn ebm_append_parity(byte: u8, even: bool) -> u16 {
+    // The parity bit completes the count of ones to the requested parity
+    let odd_data = ebm_parity(byte);
+    let frame = u16::from(byte);
+    if odd_data == even { ebm_set_bit(frame, PARITY_BIT) } else { frame }
+}
+
+/// Validates the parity bit of a framed byte and strips it
+///
+/// # Arguments
+/// * `frame` - The byte in bits 0 to 7 and its parity bit in bit 8
+/// * `even` - `true` for even parity, `false` for odd parity
+///
+/// # Returns
+/// * `Option<u8>` - The data byte, or `None` when the parity is wrong or bits 9 to 15 are set
+///
+/// # Implementation Details
+/// This function checks the parity of the whole frame:
+/// 1. Rejects frames with any bit above the parity bit
+/// 2. Computes the parity of all nine bits with `ebm_parity`
+/// 3. Accepts the frame when it matches the requested parity
+/// 4. Returns the low eight bits as the data byte
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One parity computation and a few comparisons
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::ecc::ebm_check_parity;
+/// let result = ebm_check_parity(0b1_0000_0111, true); // Some(0b0000_0111)
+/// let result = ebm_check_parity(0b1_0000_0110, true); // None (a data bit flipped)
+/// let result = ebm_check_parity(0b0_0000_0111, false); // Some(0b0000_0111)
+/// ```
+///
+/// # Function Logic
+/// Including the parity bit in the count means a single function checks both parities: the
+/// frame is valid exactly when its total count of ones is even for even parity and odd for odd
+/// parity. An even number of flipped bits keeps the parity and goes undetected.
+///
+/// # Safety Considerations
+/// - Never returns a byte from a malformed frame
+/// - No undefined behavior possible
+pub fn ebm_check_parity(frame: u16, even: bool) -> Option<u8> {
+    // A frame only has nine bits
+    if frame >> (PARITY_BIT + 1) != 0 {
+        return None;
+    }
+
+    // The total count of ones must have the requested parity
+    let odd_total = ebm_parity(frame);
+    if odd_total == even {
+        return None;
+    }
+    Some(frame as u8)
+}
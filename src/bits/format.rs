@@ -0,0 +1,332 @@
+// Number Formatting Operations for Eidolon Math Library
+// This module contains functions that convert integers to and from digit sequences and varints
+// Digits are stored least significant first so that any base can be handled uniformly
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+
+// Import the integer traits that provide the constants, conversions and sign pairing
+use crate::bits::ebm_int::{EbmInt, EbmSigned, EbmUnsigned};
+
+// Import the mask builder used for logical shifts of signed values
+use crate::bits::bit_manipulation::ebm_low_mask;
+
+// Import the basic operations the ZigZag and LEB128 encodings are built on
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::{ebm_and, ebmor, ebmxor};
+use crate::bits::bit_operations::bitwise_shifting::bitwise_shifting::{ebm_left_shift, ebm_right_shift};
+
+// Import the division helper the digit extraction is built on
+use crate::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_div::ebm_divmod;
+
+/// Splits a value into its digits in an arbitrary base
+///
+/// # Arguments
+/// * `a` - The non-negative value to convert
+/// * `base` - The base of the digits, at least 2
+///
+/// # Returns
+/// * `Vec<u32>` - The digits of `a`, least significant first, or `[0]` when `a` is zero
+///
+/// # Implementation Details
+/// This function peels off one digit per division:
+/// 1. Returns `[0]` directly for zero, which would otherwise produce no digits
+/// 2. Divides the remaining value by the base with `ebm_divmod`
+/// 3. Stores the remainder as the next digit and continues with the quotient
+/// 4. Stops when the quotient reaches zero
+///
+/// # Performance Characteristics
+/// - One heap allocation for the digit vector
+/// - One division per digit, `log_base(a)` in total
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::format::ebm_to_digits;
+/// let result = ebm_to_digits(255u32, 16); // [15, 15]
+/// let result = ebm_to_digits(1234u32, 10); // [4, 3, 2, 1]
+/// let result = ebm_to_digits(0u32, 10); // [0]
+/// ```
+///
+/// # Function Logic
+/// Least-significant-first order is the order in which division produces the digits, and it
+/// keeps digit `i` at weight `base^i`. Reversing the vector gives the usual reading order for
+/// display.
+///
+/// # Safety Considerations
+/// - `a` must not be negative and `base` must be at least 2 (checked with a debug assertion)
+/// - `base` must not exceed `u32::MAX`, otherwise digits are truncated
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_to_digits<T: EbmInt>(a: T, base: T) -> Vec<u32> {
+    // Base 0 and 1 have no positional digits
+    debug_assert!(a >= T::ZERO && base >= T::from_u32(2), "digits need a non-negative value and a base of at least 2");
+
+    // Zero is written with a single digit
+    if a == T::ZERO {
+        return vec![0];
+    }
+
+    // Every division yields the next digit
+    let mut digits = Vec::new();
+    let mut remaining = a;
+    while remaining != T::ZERO {
+        let (quotient, digit) = ebm_divmod(remaining, base);
+        digits.push(digit.to_u32());
+        remaining = quotient;
+    }
+    digits
+}
+
+/// Rebuilds a value from its digits in an arbitrary base
+///
+/// # Arguments
+/// * `digits` - The digits, least significant first
+/// * `base` - The base of the digits, at least 2
+///
+/// # Returns
+/// * `Option<T>` - The value, or `None` on overflow or when a digit is not below the base
+///
+/// # Implementation Details
+/// This function evaluates the digits with Horner's rule:
+/// 1. Walks the digits from the most significant one, at the end of the slice, down
+/// 2. Rejects digits that do not convert to `T` losslessly or are not below the base
+/// 3. Multiplies the accumulator by the base with `checked_mul` and adds the digit with `checked_add`
+/// 4. Returns `None` as soon as either operation overflows
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - One multiplication and one addition per digit
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::format::ebm_from_digits;
+/// let result = ebm_from_digits(&[15, 15], 16u32); // Some(255)
+/// let result = ebm_from_digits(&[6, 5, 2], 10u8); // None (256 does not fit in u8)
+/// let result = ebm_from_digits(&[10], 10u8); // None (10 is not a decimal digit)
+/// ```
+///
+/// # Function Logic
+/// This is the inverse of `ebm_to_digits`: `ebm_from_digits(&ebm_to_digits(a, b), b)` returns
+/// `Some(a)`. Leading zero digits are allowed, and an empty slice evaluates to zero. Checking
+/// every step makes the function safe to use on untrusted, tokenized input.
+///
+/// # Safety Considerations
+/// - `base` must be at least 2 (checked with a debug assertion)
+/// - Uses Rust's safe checked intrinsics, so overflow is reported instead of wrapping
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_from_digits<T: EbmInt>(digits: &[u32], base: T) -> Option<T> {
+    // Base 0 and 1 have no positional digits
+    debug_assert!(base >= T::from_u32(2), "digits need a base of at least 2");
+
+    let mut value = T::ZERO;
+    for &digit in digits.iter().rev() {
+        // The digit must survive the conversion and lie below the base
+        let converted = T::from_u32(digit);
+        if converted.to_u32() != digit || converted < T::ZERO || converted >= base {
+            return None;
+        }
+
+        // Shift the accumulator up one position and add the digit
+        value = value.checked_mul(base)?.checked_add(converted)?;
+    }
+    Some(value)
+}
+
+/// Maps a signed value to an unsigned one so that small magnitudes stay small (ZigZag encoding)
+///
+/// # Arguments
+/// * `a` - The signed value to encode
+///
+/// # Returns
+/// * `T::Unsigned` - `2 * a` for non-negative values and `-2 * a - 1` for negative values
+///
+/// # Implementation Details
+/// This function uses the branchless formula `(n << 1) ^ (n >> (width - 1))`:
+/// 1. Shifts the value left by one, freeing bit 0 for the sign
+/// 2. Shifts it arithmetically right by `width - 1`, giving all zeros or all ones
+/// 3. XORs both, which inverts the doubled value exactly when it was negative
+/// 4. Reinterprets the bit pattern as the unsigned type of the same width
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Two shifts and one XOR, no branches
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::format::ebm_zigzag_encode;
+/// let result = ebm_zigzag_encode(0i32); // 0u32
+/// let result = ebm_zigzag_encode(-1i32); // 1u32
+/// let result = ebm_zigzag_encode(1i32); // 2u32
+/// let result = ebm_zigzag_encode(i8::MIN); // 255u8
+/// ```
+///
+/// # Function Logic
+/// Varint encodings spend bytes on the position of the highest set bit, so a two's-complement
+/// `-1` would take the maximum length. ZigZag interleaves the values as `0, -1, 1, -2, 2, ...`,
+/// which is the preprocessing step protobuf applies to `sint` fields.
+///
+/// # Safety Considerations
+/// - The left shift deliberately drops the sign bit, which the right shift has captured
+/// - No undefined behavior and no overflow panic in any build mode
+pub fn ebm_zigzag_encode<T: EbmSigned>(a: T) -> T::Unsigned {
+    // Double the value and fold the sign into bit 0
+    ebmxor(ebm_left_shift(a, 1u32), ebm_right_shift(a, T::BITS - 1)).reinterpret_unsigned()
+}
+
+/// Maps a ZigZag-encoded unsigned value back to the signed value it came from
+///
+/// # Arguments
+/// * `a` - The unsigned value produced by `ebm_zigzag_encode`
+///
+/// # Returns
+/// * `U::Signed` - `a / 2` for even values and `-(a + 1) / 2` for odd values
+///
+/// # Implementation Details
+/// This function inverts the encoding with `(n >> 1) ^ -(n & 1)`:
+/// 1. Shifts the value logically right by one to recover the magnitude bits
+/// 2. Negates bit 0 with wrapping arithmetic, giving all zeros or all ones
+/// 3. XORs both, which restores the inverted bits of negative values
+/// 4. Reinterprets the bit pattern as the signed type of the same width
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One shift, one AND, one negation and one XOR, no branches
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::format::ebm_zigzag_decode;
+/// let result = ebm_zigzag_decode(1u32); // -1i32
+/// let result = ebm_zigzag_decode(2u32); // 1i32
+/// let result = ebm_zigzag_decode(255u8); // -128i8
+/// ```
+///
+/// # Function Logic
+/// The encoding is a bijection between the signed and unsigned types of the same width, so
+/// every unsigned value decodes to exactly one signed value and round trips are lossless.
+///
+/// # Safety Considerations
+/// - Uses wrapping negation, so no overflow panic in any build mode
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_zigzag_decode<U: EbmUnsigned>(a: U) -> U::Signed {
+    // Recover the magnitude and undo the inversion of negative values
+    ebmxor(ebm_right_shift(a, 1u32), ebm_and(a, U::ONE).wrapping_neg()).reinterpret_signed()
+}
+
+/// Appends the unsigned LEB128 encoding of a value to a buffer
+///
+/// # Arguments
+/// * `a` - The value to encode, taken as an unsigned bit pattern
+/// * `out` - The buffer the encoded bytes are appended to
+///
+/// # Implementation Details
+/// This function emits seven bits per byte, least significant group first:
+/// 1. Takes the low seven bits of the remaining value as the payload
+/// 2. Shifts the value logically right by seven, so signed values cannot sign-fill
+/// 3. Sets the continuation bit 0x80 when any bits remain, then appends the byte
+/// 4. Stops after the first byte without a continuation bit; zero encodes as a single byte
+///
+/// # Performance Characteristics
+/// - Appends `max(1, ceil(bit_length / 7))` bytes, reallocating only when the buffer is full
+/// - One shift, one mask and one push per byte
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::format::ebm_leb128_encode;
+/// let mut out = Vec::new();
+/// ebm_leb128_encode(300u32, &mut out); // out = [0xAC, 0x02]
+/// ebm_leb128_encode(0u32, &mut out); // out = [0xAC, 0x02, 0x00]
+/// ```
+///
+/// # Function Logic
+/// LEB128 stores small numbers in few bytes while still allowing the full range of the type.
+/// Signed values are written as their two's-complement bit pattern, so negative values take
+/// the maximum length; apply `ebm_zigzag_encode` first to keep small negatives short.
+///
+/// # Safety Considerations
+/// - Never shifts by the full width or more
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_leb128_encode<T: EbmInt>(a: T, out: &mut Vec<u8>) {
+    let payload_mask = ebm_low_mask::<T>(7);
+    let remaining_mask = ebm_low_mask::<T>(T::BITS.saturating_sub(7));
+    let mut value = a;
+
+    loop {
+        // Take the next seven bits and shift them out logically
+        let payload = ebm_and(value, payload_mask).to_u32() as u8;
+        value = if T::BITS > 7 { ebm_and(ebm_right_shift(value, 7u32), remaining_mask) } else { T::ZERO };
+
+        // Flag every byte but the last as continued
+        if value == T::ZERO {
+            out.push(payload);
+            return;
+        }
+        out.push(payload | 0x80);
+    }
+}
+
+/// Decodes an unsigned LEB128 value from the start of a buffer
+///
+/// # Arguments
+/// * `data` - The buffer starting with the encoded value
+///
+/// # Returns
+/// * `Option<(T, usize)>` - The value and the number of bytes consumed, or `None` when the input
+///   ends before the last byte or the value does not fit in `T`
+///
+/// # Implementation Details
+/// This function accumulates seven bits per byte, least significant group first:
+/// 1. Masks the payload of every byte with 0x7F
+/// 2. Rejects payload bits that would land at or above the bit width
+/// 3. ORs the payload into the result at the current group position
+/// 4. Returns as soon as a byte without the continuation bit 0x80 is read
+/// 5. Returns `None` when the buffer runs out first
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Linear time in the encoded length
+/// - One shift, one mask and one OR per byte
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::format::ebm_leb128_decode;
+/// let result = ebm_leb128_decode::<u32>(&[0xAC, 0x02, 0xFF]); // Some((300, 2))
+/// let result = ebm_leb128_decode::<u32>(&[0xAC]); // None (truncated)
+/// let result = ebm_leb128_decode::<u8>(&[0x80, 0x02]); // None (256 does not fit)
+/// ```
+///
+/// # Function Logic
+/// The consumed length lets callers decode a stream of values back to back. Zero-valued
+/// padding groups beyond the width are accepted, since they do not change the value; only set
+/// bits that cannot be represented count as an overflow.
+///
+/// # Safety Considerations
+/// - Never shifts by the full width or more
+/// - Uses only safe iteration over the slice
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_leb128_decode<T: EbmInt>(data: &[u8]) -> Option<(T, usize)> {
+    let mut result = T::ZERO;
+    let mut shift = 0u32;
+
+    for (index, &byte) in data.iter().enumerate() {
+        let payload = u32::from(byte & 0x7F);
+
+        // Payload bits at or above the width would be lost
+        if shift >= T::BITS {
+            if payload != 0 {
+                return None;
+            }
+        } else {
+            if T::BITS - shift < 7 && payload >> (T::BITS - shift) != 0 {
+                return None;
+            }
+            result = ebmor(result, ebm_left_shift(T::from_u32(payload), shift));
+        }
+
+        // The first byte without a continuation bit ends the value
+        if byte & 0x80 == 0 {
+            return Some((result, index + 1));
+        }
+        shift = shift.saturating_add(7);
+    }
+
+    // The buffer ended inside the value
+    None
+}
@@ -0,0 +1,149 @@
+// Linear Feedback Shift Register for Eidolon Math Library
+// This module contains a configurable LFSR for pseudorandom bit sequences
+// Both the Fibonacci (external XOR) and Galois (internal XOR) configurations are supported
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+
+// Import the integer trait that provides the bit width and constants
+use crate::bits::ebm_int::EbmInt;
+
+// Import the mask builder used to keep the register inside its width
+use crate::bits::bit_manipulation::ebm_low_mask;
+
+// Import the parity used as the Fibonacci feedback bit
+use crate::bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_parity;
+
+// Import the basic operations the register steps are built on
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::{ebm_and, ebmor, ebmxor};
+use crate::bits::bit_operations::bitwise_shifting::bitwise_shifting::{ebm_left_shift, ebm_right_shift};
+
+/// The feedback arrangement of an `Lfsr`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LfsrMode {
+    /// The parity of all tapped bits is shifted in at the top
+    Fibonacci,
+    /// The output bit is XORed into every tapped position while shifting
+    Galois,
+}
+
+/// A linear feedback shift register of configurable width and taps
+///
+/// # Implementation Details
+/// The register shifts right by one position per step and outputs its lowest bit:
+/// 1. Fibonacci mode computes the parity of `state & taps` and shifts it in at bit `width - 1`
+/// 2. Galois mode shifts the state and, when the output bit is set, XORs the tap mask into it
+/// 3. Bits at and above `width` are always kept at 0
+///
+/// For a feedback polynomial of degree `width`, Galois mode uses the mask with bit `k - 1` set
+/// for every term `x^k` (e.g. `0xB8` for `x^8 + x^6 + x^5 + x^4 + 1`), and Fibonacci mode uses
+/// the mask with bit `width - k` set for every term `x^k` with `k < width`, plus bit 0 for the
+/// `x^width` term itself (e.g. `0x1D` for the same polynomial).
+///
+/// # Performance Characteristics
+/// - No heap memory; the register is a single integer plus its configuration
+/// - Constant-time steps: one shift, one mask and a parity or XOR
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::lfsr::{Lfsr, LfsrMode};
+/// let mut lfsr = Lfsr::new(LfsrMode::Galois, 4, 0b1100u8, 1); // x^4 + x^3 + 1
+/// let state = lfsr.step(); // 0b1100
+/// let bit = lfsr.next_bit(); // false
+/// ```
+///
+/// # Safety Considerations
+/// - Construction panics for widths outside the type, taps outside the width or a zero seed
+/// - A zero state would never change, so it is rejected instead of silently locking up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lfsr<T: EbmInt> {
+    // The feedback arrangement
+    mode: LfsrMode,
+    // The number of bits in the register
+    width: u32,
+    // The tapped positions, interpreted according to the mode
+    taps: T,
+    // The current register contents
+    state: T,
+}
+
+impl<T: EbmInt> Lfsr<T> {
+    /// Creates a register with the given configuration and seed
+    ///
+    /// # Arguments
+    /// * `mode` - The Fibonacci or Galois feedback arrangement
+    /// * `width` - The number of bits in the register, between 1 and the bit width of `T`
+    /// * `taps` - The tap mask, which must fit in `width` bits
+    /// * `seed` - The initial state, which must be non-zero and fit in `width` bits
+    ///
+    /// # Returns
+    /// * `Lfsr<T>` - The seeded register
+    ///
+    /// # Panics
+    /// Panics when the width, the taps or the seed are out of range
+    pub fn new(mode: LfsrMode, width: u32, taps: T, seed: T) -> Self {
+        // The register and everything stored in it has to fit in the type
+        assert!(width >= 1 && width <= T::BITS, "LFSR width {} out of range", width);
+        let mask = ebm_low_mask::<T>(width);
+        assert!(ebm_and(taps, mask) == taps, "LFSR taps exceed the register width");
+        assert!(ebm_and(seed, mask) == seed, "LFSR seed exceeds the register width");
+
+        // The all-zero state is a fixed point of every LFSR
+        assert!(seed != T::ZERO, "LFSR seed must be non-zero");
+
+        Self {
+            mode,
+            width,
+            taps,
+            state: seed,
+        }
+    }
+
+    /// Returns the current register contents
+    ///
+    /// # Returns
+    /// * `T` - The state, with all bits at and above the width clear
+    pub fn state(&self) -> T {
+        self.state
+    }
+
+    /// Advances the register by one step and returns the bit shifted out
+    ///
+    /// # Returns
+    /// * `bool` - The lowest bit of the state before the step
+    ///
+    /// # Function Logic
+    /// Both modes shift right with the low bit as output, so the output sequence of a
+    /// maximal-length register repeats only after `2^width - 1` bits.
+    pub fn next_bit(&mut self) -> bool {
+        let output = ebm_and(self.state, T::ONE) == T::ONE;
+
+        // Shift logically, even when the register fills a signed type
+        let shifted = ebm_and(ebm_right_shift(self.state, 1u32), ebm_low_mask::<T>(self.width - 1));
+
+        self.state = match self.mode {
+            LfsrMode::Fibonacci => {
+                // Feed the parity of the tapped bits back in at the top
+                let parity = ebm_parity(ebm_and(self.state, self.taps));
+                ebmor(shifted, ebm_left_shift(T::from_u32(u32::from(parity)), self.width - 1))
+            }
+            LfsrMode::Galois => {
+                // Toggle the tapped positions whenever a one is shifted out
+                if output {
+                    ebmxor(shifted, self.taps)
+                } else {
+                    shifted
+                }
+            }
+        };
+
+        output
+    }
+
+    /// Advances the register by one step and returns the new state
+    ///
+    /// # Returns
+    /// * `T` - The state after the step
+    pub fn step(&mut self) -> T {
+        self.next_bit();
+        self.state
+    }
+}
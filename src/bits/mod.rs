@@ -5,8 +5,48 @@
 // This file acts as the entry point for the bits system
 // It will import and organize all the bit-related functionality
 
+// Import the integer trait shared by the generic bit functions
+pub mod ebm_int;
+
 // Import the bit operations module
 pub mod bit_operations;
 
+// Import the bit manipulation module
+pub mod bit_manipulation;
+
+// Import the bit field struct generator
+pub mod bitfield;
+
+// Import the bit analysis module
+pub mod analysis;
+
+// Import the packed bit set containers
+pub mod bitset;
+
+// Import the bit matrix operations
+pub mod matrix;
+
+// Import the Morton order interleaving operations
+pub mod morton;
+
+// Import the linear feedback shift register
+pub mod lfsr;
+
+// Import the digit conversion operations
+pub mod format;
+
+// Import the constant-time operations for secret data
+pub mod constant_time;
+
+// Import the error-correcting codes
+pub mod ecc;
+
+// Import the byte stream readers
+pub mod stream;
+
+// Import the test vector generators, only available with the `testing` feature
+#[cfg(feature = "testing")]
+pub mod testing;
+
 // Re-export commonly used bit operations for easy access
 // This will be populated as we create the actual bit operation modules
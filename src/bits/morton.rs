@@ -0,0 +1,310 @@
+// Morton Order Operations for Eidolon Math Library
+// This module contains functions that interleave and deinterleave bits for space-filling curves
+// Coordinates are spread apart by a stride so several of them can share one integer
+// Built on the bit operations from the bit_operations module wherever possible
+
+// Import the integer traits that provide the bit width, constants and double-width partners
+use crate::bits::ebm_int::{EbmInt, EbmNarrow, EbmWiden};
+
+// Import the mask builder used for the deinterleave masks
+use crate::bits::bit_manipulation::ebm_low_mask;
+
+// Import the basic operations the mask-based deinterleave is built on
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::{ebm_and, ebmor};
+use crate::bits::bit_operations::bitwise_shifting::bitwise_shifting::{ebm_left_shift, ebm_right_shift};
+
+// Import the single-bit primitives used to move bits one position at a time
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_and::ebm_test_bit;
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_or::ebm_set_bit;
+
+/// Spreads the bits of a value apart by a fixed stride
+///
+/// # Arguments
+/// * `a` - The value whose low bits are spread
+/// * `stride` - The distance between consecutive output bits, at least 1
+///
+/// # Returns
+/// * `T` - A value where input bit `k` is at position `k * stride` and all other bits are clear
+///
+/// # Implementation Details
+/// This function moves every input bit to its strided position:
+/// 1. Visits the input bits `k = 0, 1, 2, ...` while `k * stride` lies inside the type
+/// 2. Reads each input bit with `ebm_test_bit`
+/// 3. Sets output bit `k * stride` with `ebm_set_bit` when the input bit is set
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One test and at most one set per output position, `width / stride` steps in total
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::morton::ebm_spread_bits;
+/// let result = ebm_spread_bits(0b111u8, 2); // 0b01_0101
+/// let result = ebm_spread_bits(0b11u16, 3); // 0b1001
+/// let result = ebm_spread_bits(0b1011u8, 1); // 0b1011 (stride 1 is the identity)
+/// ```
+///
+/// # Function Logic
+/// A Morton code for `n` dimensions spreads every coordinate with stride `n` and ORs the
+/// results together after shifting coordinate `i` up by `i`. Any number of dimensions works
+/// the same way. Input bits whose target position lies beyond the width are discarded.
+///
+/// # Safety Considerations
+/// - `stride` must be at least 1 (checked with a debug assertion)
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_spread_bits<T: EbmInt>(a: T, stride: u32) -> T {
+    // A zero stride would put every bit on position 0
+    debug_assert!(stride >= 1, "stride must be at least 1");
+
+    // Move input bit k to position k * stride
+    let mut result = T::ZERO;
+    let mut source = 0;
+    let mut target = 0;
+    while target < T::BITS {
+        if ebm_test_bit(a, source) {
+            result = ebm_set_bit(result, target);
+        }
+        source += 1;
+        target += stride;
+    }
+    result
+}
+
+/// Gathers the bits at a fixed stride into contiguous low bits
+///
+/// # Arguments
+/// * `a` - The value whose strided bits are collected
+/// * `stride` - The distance between consecutive input bits, at least 1
+///
+/// # Returns
+/// * `T` - A value where output bit `k` is input bit `k * stride` and all higher bits are clear
+///
+/// # Implementation Details
+/// This function is the inverse of `ebm_spread_bits`:
+/// 1. Visits the input positions `0, stride, 2 * stride, ...` that lie inside the type
+/// 2. Reads each of them with `ebm_test_bit`
+/// 3. Sets the next contiguous output bit with `ebm_set_bit` when the input bit is set
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One test and at most one set per input position, `width / stride` steps in total
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::morton::ebm_gather_bits;
+/// let result = ebm_gather_bits(0b01_0101u8, 2); // 0b111
+/// let result = ebm_gather_bits(0b10_1010u8 >> 1, 2); // 0b111 (the odd bits, shifted down first)
+/// let result = ebm_gather_bits(0b1001u16, 3); // 0b11
+/// ```
+///
+/// # Function Logic
+/// Decoding a Morton code shifts the code down by the index of a coordinate and gathers with
+/// the number of dimensions as the stride. Bits between the strided positions are ignored, so
+/// `ebm_gather_bits(ebm_spread_bits(x, s), s)` returns `x` for every `x` that fits.
+///
+/// # Safety Considerations
+/// - `stride` must be at least 1 (checked with a debug assertion)
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_gather_bits<T: EbmInt>(a: T, stride: u32) -> T {
+    // A zero stride would read position 0 forever
+    debug_assert!(stride >= 1, "stride must be at least 1");
+
+    // Move input bit k * stride to position k
+    let mut result = T::ZERO;
+    let mut source = 0;
+    let mut target = 0;
+    while source < T::BITS {
+        if ebm_test_bit(a, source) {
+            result = ebm_set_bit(result, target);
+        }
+        source += stride;
+        target += 1;
+    }
+    result
+}
+
+// Builds the mask with `ones` set bits at the bottom of every `2 * ones`-bit group
+fn deinterleave_mask<T: EbmInt>(ones: u32) -> T {
+    let mut mask = ebm_low_mask::<T>(ones);
+    let mut filled = 2 * ones;
+
+    // Double the covered width until the pattern fills the type
+    while filled < T::BITS {
+        mask = ebmor(mask, ebm_left_shift(mask, filled));
+        filled *= 2;
+    }
+    mask
+}
+
+/// Compacts the bits at even positions into the low half of the value
+///
+/// # Arguments
+/// * `a` - The value holding two interleaved channels
+///
+/// # Returns
+/// * `T` - Bits `0, 2, 4, ...` of `a` moved to positions `0, 1, 2, ...`; the high half is clear
+///
+/// # Implementation Details
+/// This function uses the classic deinterleave cascade:
+/// 1. Keeps only the even bits with the mask `0x55..55`
+/// 2. Folds every pair of 1-bit groups together with `x | (x >> 1)` and the mask `0x33..33`
+/// 3. Repeats with shifts of 2, 4, 8, ... and the masks `0x0F..0F`, `0x00FF..00FF`, ...
+/// 4. Stops once the groups cover half of the type
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - `log2(width)` steps of a shift, an OR and an AND, no per-bit loop
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::morton::ebm_even_bits;
+/// let result = ebm_even_bits(0b1111u8); // 0b11
+/// let result = ebm_even_bits(0b0101_0001u8); // 0b1101
+/// ```
+///
+/// # Function Logic
+/// This is the one-dimensional Morton decode: the even bits of a 2D Morton code are the x
+/// coordinate. It gives the same result as `ebm_gather_bits(a, 2)`, but with a fixed number of
+/// mask steps instead of one step per bit.
+///
+/// # Safety Considerations
+/// - Never shifts by the full width or more
+/// - No undefined behavior possible with valid numeric types
+/// - Signed values are treated as their two's-complement bit pattern
+pub fn ebm_even_bits<T: EbmInt>(a: T) -> T {
+    // Keep one bit of every pair
+    let mut x = ebm_and(a, deinterleave_mask::<T>(1));
+
+    // Close the gaps between ever larger groups
+    let mut group = 1;
+    while group < T::BITS / 2 {
+        x = ebm_and(ebmor(x, ebm_right_shift(x, group)), deinterleave_mask::<T>(2 * group));
+        group *= 2;
+    }
+    x
+}
+
+/// Compacts the bits at odd positions into the low half of the value
+///
+/// # Arguments
+/// * `a` - The value holding two interleaved channels
+///
+/// # Returns
+/// * `T` - Bits `1, 3, 5, ...` of `a` moved to positions `0, 1, 2, ...`; the high half is clear
+///
+/// # Implementation Details
+/// This function reuses the even-bit cascade:
+/// 1. Shifts the value right by one so the odd bits land on even positions
+/// 2. Compacts them with `ebm_even_bits`, whose first mask also removes any sign-fill bit
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - One shift more than `ebm_even_bits`
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::morton::ebm_odd_bits;
+/// let result = ebm_odd_bits(0b1111u8); // 0b11
+/// let result = ebm_odd_bits(0b1010_0010u8); // 0b1101
+/// ```
+///
+/// # Function Logic
+/// The odd bits of a 2D Morton code are the y coordinate. Together with `ebm_even_bits` this
+/// splits two interleaved channels back into separate values.
+///
+/// # Safety Considerations
+/// - Never shifts by the full width or more
+/// - No undefined behavior possible with valid numeric types
+/// - Signed values are treated as their two's-complement bit pattern
+pub fn ebm_odd_bits<T: EbmInt>(a: T) -> T {
+    // Move the odd bits onto the even positions
+    ebm_even_bits(ebm_right_shift(a, 1u32))
+}
+
+/// Interleaves the bits of two values into one value of twice the width
+///
+/// # Arguments
+/// * `a` - The value whose bits go to the even positions
+/// * `b` - The value whose bits go to the odd positions
+///
+/// # Returns
+/// * `T::Wide` - A value where bit `2k` is bit `k` of `a` and bit `2k + 1` is bit `k` of `b`
+///
+/// # Implementation Details
+/// This function zips the two values with stride-2 spreads:
+/// 1. Zero-extends both inputs to the double-width type with `widen`
+/// 2. Spreads each of them with `ebm_spread_bits` and a stride of 2
+/// 3. Shifts the spread bits of `b` up by one and ORs both together
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Two spreads of `width` steps each, one shift and one OR
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::morton::ebm_interleave;
+/// let result = ebm_interleave(0b11u8, 0b00u8); // 0b0101u16
+/// let result = ebm_interleave(0b00u8, 0b11u8); // 0b1010u16
+/// let result = ebm_interleave(u32::MAX, 0u32); // 0x5555_5555_5555_5555u64
+/// ```
+///
+/// # Function Logic
+/// This is the two-dimensional Morton code with `a` as the x and `b` as the y coordinate, but
+/// the result type is chosen from the inputs so no bit is ever dropped. It is defined for the
+/// unsigned types that have a double-width partner, `u8` up to `u64`.
+///
+/// # Safety Considerations
+/// - The result has room for every bit of both inputs
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_interleave<T: EbmWiden>(a: T, b: T) -> T::Wide {
+    let even = ebm_spread_bits(a.widen(), 2);
+    let odd = ebm_spread_bits(b.widen(), 2);
+    ebmor(even, ebm_left_shift(odd, 1u32))
+}
+
+/// Splits a value into its even-position and odd-position bits as two half-width values
+///
+/// # Arguments
+/// * `a` - The interleaved value to split
+///
+/// # Returns
+/// * `(U::Narrow, U::Narrow)` - The bits at even positions and the bits at odd positions, each
+///   packed into the low bits of a half-width value
+///
+/// # Implementation Details
+/// This function is the inverse of `ebm_interleave`:
+/// 1. Gathers the even positions with `ebm_gather_bits` and a stride of 2
+/// 2. Shifts the value down by one and gathers again for the odd positions
+/// 3. Truncates both halves to the half-width type with `narrow`
+///
+/// # Performance Characteristics
+/// - Zero heap allocations
+/// - Minimal stack usage
+/// - Two gathers of `width / 2` steps each and one shift
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::morton::ebm_deinterleave;
+/// let result = ebm_deinterleave(0b0101u16); // (0b11u8, 0b00u8)
+/// let result = ebm_deinterleave(0xC000u16); // (0x80u8, 0x80u8)
+/// let result = ebm_deinterleave(u128::MAX); // (u64::MAX, u64::MAX)
+/// ```
+///
+/// # Function Logic
+/// Every gathered half holds exactly `width / 2` bits, so narrowing never drops a set bit and
+/// `ebm_deinterleave(ebm_interleave(a, b))` returns `(a, b)` for all inputs. It is defined for
+/// the unsigned types that have a half-width partner, `u16` up to `u128`.
+///
+/// # Safety Considerations
+/// - Unsigned inputs make the shift logical, so no sign bits are smeared into the odd half
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_deinterleave<U: EbmNarrow>(a: U) -> (U::Narrow, U::Narrow) {
+    let even = ebm_gather_bits(a, 2);
+    let odd = ebm_gather_bits(ebm_right_shift(a, 1u32), 2);
+    (even.narrow(), odd.narrow())
+}
@@ -0,0 +1,161 @@
+// Byte Stream Readers for Eidolon Math Library
+// This module contains cursors that read integers from byte buffers for binary format parsing
+// Every read is bounds-checked and reports a short buffer instead of panicking
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+
+// Import the integer trait that provides the width and the byte slice constructors
+use crate::bits::ebm_int::EbmInt;
+
+/// The byte order of a multi-byte integer in a buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endian {
+    /// The least significant byte comes first
+    Little,
+    /// The most significant byte comes first
+    Big,
+}
+
+/// A read position over a byte buffer that decodes byte-aligned integers
+///
+/// # Implementation Details
+/// The cursor borrows the buffer and keeps the offset of the next unread byte:
+/// 1. Every read first checks that enough bytes remain
+/// 2. A short read returns `None` and leaves the position unchanged
+/// 3. A successful read decodes the bytes with `from_le_bytes` or `from_be_bytes` and advances
+///    the position past them
+///
+/// # Performance Characteristics
+/// - No heap memory; the cursor is a slice and an offset
+/// - Constant-time reads: one bounds check and one byte-order conversion
+///
+/// # Examples
+/// ```
+/// use eidolon_math::bits::stream::{ByteCursor, Endian};
+/// let mut cursor = ByteCursor::new(&[0x34, 0x12, 0x00, 0x00, 0x00, 0x2A]);
+/// let tag = cursor.read_u16_le(); // Some(0x1234)
+/// let length = cursor.read::<u32>(Endian::Big); // Some(42)
+/// let past_end = cursor.read_u8(); // None
+/// ```
+///
+/// # Safety Considerations
+/// - Reads never index past the end of the buffer
+/// - The position only ever moves forward by whole values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ByteCursor<'a> {
+    // The buffer being parsed
+    data: &'a [u8],
+    // The offset of the next unread byte
+    position: usize,
+}
+
+impl<'a> ByteCursor<'a> {
+    /// Creates a cursor at the start of a buffer
+    ///
+    /// # Arguments
+    /// * `data` - The buffer to read from
+    ///
+    /// # Returns
+    /// * `ByteCursor` - A cursor positioned at byte 0
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    /// Returns the offset of the next unread byte
+    ///
+    /// # Returns
+    /// * `usize` - The number of bytes consumed so far
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bytes left to read
+    ///
+    /// # Returns
+    /// * `usize` - The distance from the position to the end of the buffer
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    // Consumes the next `count` bytes, or nothing when fewer remain
+    fn take(&mut self, count: usize) -> Option<&'a [u8]> {
+        let end = self.position.checked_add(count)?;
+        let bytes = self.data.get(self.position..end)?;
+        self.position = end;
+        Some(bytes)
+    }
+
+    /// Reads an integer of any width in the given byte order
+    ///
+    /// # Arguments
+    /// * `endian` - The byte order of the value in the buffer
+    ///
+    /// # Returns
+    /// * `Option<T>` - The decoded value, or `None` when fewer than `T::BITS / 8` bytes remain
+    ///
+    /// # Function Logic
+    /// The value occupies exactly the size of `T`. Signed types are decoded as two's complement,
+    /// the same as `from_le_bytes` and `from_be_bytes` on the primitive type.
+    pub fn read<T: EbmInt>(&mut self, endian: Endian) -> Option<T> {
+        let bytes = self.take((T::BITS / 8) as usize)?;
+        Some(match endian {
+            Endian::Little => T::from_le_slice(bytes),
+            Endian::Big => T::from_be_slice(bytes),
+        })
+    }
+
+    /// Reads a single byte
+    ///
+    /// # Returns
+    /// * `Option<u8>` - The byte, or `None` at the end of the buffer
+    pub fn read_u8(&mut self) -> Option<u8> {
+        self.read(Endian::Little)
+    }
+
+    /// Reads a little-endian `u16`
+    ///
+    /// # Returns
+    /// * `Option<u16>` - The value, or `None` when fewer than 2 bytes remain
+    pub fn read_u16_le(&mut self) -> Option<u16> {
+        self.read(Endian::Little)
+    }
+
+    /// Reads a big-endian `u16`
+    ///
+    /// # Returns
+    /// * `Option<u16>` - The value, or `None` when fewer than 2 bytes remain
+    pub fn read_u16_be(&mut self) -> Option<u16> {
+        self.read(Endian::Big)
+    }
+
+    /// Reads a little-endian `u32`
+    ///
+    /// # Returns
+    /// * `Option<u32>` - The value, or `None` when fewer than 4 bytes remain
+    pub fn read_u32_le(&mut self) -> Option<u32> {
+        self.read(Endian::Little)
+    }
+
+    /// Reads a big-endian `u32`
+    ///
+    /// # Returns
+    /// * `Option<u32>` - The value, or `None` when fewer than 4 bytes remain
+    pub fn read_u32_be(&mut self) -> Option<u32> {
+        self.read(Endian::Big)
+    }
+
+    /// Reads a little-endian `u64`
+    ///
+    /// # Returns
+    /// * `Option<u64>` - The value, or `None` when fewer than 8 bytes remain
+    pub fn read_u64_le(&mut self) -> Option<u64> {
+        self.read(Endian::Little)
+    }
+
+    /// Reads a big-endian `u64`
+    ///
+    /// # Returns
+    /// * `Option<u64>` - The value, or `None` when fewer than 8 bytes remain
+    pub fn read_u64_be(&mut self) -> Option<u64> {
+        self.read(Endian::Big)
+    }
+}
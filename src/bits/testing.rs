@@ -0,0 +1,147 @@
+// Bit Testing Helpers for Eidolon Math Library
+// This module contains generators for canonical test vectors of bit operations
+// It is only compiled with the `testing` feature so release builds stay lean
+// Supporting all numeric types: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+
+// Import the integer trait that provides the bit width and constants
+use crate::bits::ebm_int::EbmInt;
+
+// Import the single-bit primitive used for walking-bit patterns
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_or::ebm_set_bit;
+
+// Import the byte replication used for repeating patterns
+use crate::bits::bit_manipulation::ebm_broadcast;
+
+// Import the XOR used to measure output differences
+use crate::bits::bit_operations::bitwise_logic::bitwise_logic::ebmxor;
+
+// Import the mixer used to spread sample inputs over the whole width
+use crate::bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_mix;
+
+/// Returns a canonical set of interesting bit patterns for exercising bit operations
+///
+/// # Returns
+/// * `Vec<T>` - Every distinct pattern, in a stable order
+///
+/// # Implementation Details
+/// The set covers the values that most often expose bit-manipulation bugs:
+/// 1. Zero, all ones, `T::MIN` and `T::MAX` (which differ for signed types)
+/// 2. The alternating patterns `0xAA..` and `0x55..`
+/// 3. The low and high nibble masks `0x0F..` and `0xF0..`
+/// 4. A walking one through every bit position of the type
+/// 5. A walking zero (the complement of the walking one) through every bit position
+/// 6. Duplicates are removed while keeping the first occurrence
+///
+/// # Performance Characteristics
+/// - One heap allocation holding about `2 * width + 8` values
+/// - Intended for test setup, not hot paths
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "testing")]
+/// # {
+/// use eidolon_math::bits::testing::ebm_bit_patterns;
+/// let patterns = ebm_bit_patterns::<u8>(); // 0, 0xFF, 0x7F, 0xAA, 0x55, 0x0F, 0xF0, 0x01, 0x02, ...
+/// # }
+/// ```
+///
+/// # Function Logic
+/// Because the walking patterns are generated from the bit width of `T`, every bit position is
+/// covered for every type, including `u128` and the platform-dependent `usize`.
+///
+/// # Safety Considerations
+/// - Uses only safe operations
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_bit_patterns<T: EbmInt>() -> Vec<T> {
+    // Start with the boundary values and the repeating byte patterns
+    let mut candidates = vec![
+        T::ZERO,
+        !T::ZERO,
+        T::MIN,
+        T::MAX,
+        ebm_broadcast::<T>(0xAA),
+        ebm_broadcast::<T>(0x55),
+        ebm_broadcast::<T>(0x0F),
+        ebm_broadcast::<T>(0xF0),
+    ];
+
+    // Walk a single one and a single zero through every bit position
+    for index in 0..T::BITS {
+        candidates.push(ebm_set_bit(T::ZERO, index));
+    }
+    for index in 0..T::BITS {
+        candidates.push(!ebm_set_bit(T::ZERO, index));
+    }
+
+    // Keep the first occurrence of every pattern
+    let mut patterns = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        if !patterns.contains(&candidate) {
+            patterns.push(candidate);
+        }
+    }
+
+    patterns
+}
+
+/// Measures how strongly single-bit input changes spread through a function
+///
+/// # Arguments
+/// * `f` - The function under test
+/// * `samples` - The number of pseudo-random inputs to probe
+///
+/// # Returns
+/// * `f64` - The average fraction of output bits that flip per flipped input bit, or 0.0 when
+///   `samples` is zero
+///
+/// # Implementation Details
+/// This function probes the strict avalanche criterion:
+/// 1. Derives each sample input deterministically by mixing the sample index
+/// 2. Flips every input bit in turn and evaluates `f` on both inputs
+/// 3. Counts the differing output bits with XOR and popcount
+/// 4. Divides the total by `samples * width * width`
+///
+/// # Performance Characteristics
+/// - Calls `f` `samples * (width + 1)` times
+/// - Zero heap allocations
+/// - Deterministic, so results are reproducible across runs
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "testing")]
+/// # {
+/// use eidolon_math::bits::testing::ebm_avalanche_score;
+/// let result = ebm_avalanche_score(|x: u64| x, 16); // 1/64 (one output bit per input bit)
+/// let result = ebm_avalanche_score(|x: u64| !x, 16); // 1/64 as well
+/// # }
+/// ```
+///
+/// # Function Logic
+/// A good hash or mixer flips each output bit with probability one half whenever any single
+/// input bit changes, so its score is close to 0.5. Functions that leave bits in place, such
+/// as the identity or a bit permutation, score `1 / width`.
+///
+/// # Safety Considerations
+/// - Uses only safe operations
+/// - No undefined behavior possible with valid numeric types
+pub fn ebm_avalanche_score<T: EbmInt>(f: impl Fn(T) -> T, samples: usize) -> f64 {
+    if samples == 0 {
+        return 0.0;
+    }
+
+    let mut flipped = 0u64;
+    for sample in 0..samples {
+        // Spread the sample index so every input bit varies between samples
+        let input = ebm_mix(T::from_u32(sample as u32), T::from_u32(0x9E37_79B9));
+        let output = f(input);
+
+        // Flip each input bit and count the output bits that change
+        for index in 0..T::BITS {
+            let changed = f(ebmxor(input, ebm_set_bit(T::ZERO, index)));
+            flipped += u64::from(ebmxor(output, changed).count_ones());
+        }
+    }
+
+    let width = f64::from(T::BITS);
+    flipped as f64 / (samples as f64 * width * width)
+}
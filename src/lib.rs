@@ -6,7 +6,9 @@
 pub mod bits;
 
 // Comprehensive tests for GitHub Actions - now including real bitwise function tests
+// The sanity tests deliberately compare constant expressions against known results
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants, clippy::identity_op, clippy::erasing_op)]
 mod tests {
     use super::*;
 
@@ -262,4 +264,2697 @@ mod tests {
         assert_eq!(ebm_and(u8::MAX, 0u8), 0u8);
         assert_eq!(ebm_and(0u8, u8::MAX), 0u8);
     }
+
+    // Test checked shifts reject amounts at or beyond the bit width
+    #[test]
+    fn test_ebm_checked_shifts() {
+        use bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_shift::*;
+
+        // Test valid left shifts up to width - 1
+        assert_eq!(ebm_checked_shl(1u8, 0), Some(1u8));
+        assert_eq!(ebm_checked_shl(1u8, 7), Some(0x80u8));
+        assert_eq!(ebm_checked_shl(1u64, 63), Some(1u64 << 63));
+
+        // Test left shifts exactly at and beyond the bit width
+        assert_eq!(ebm_checked_shl(1u8, 8), None);
+        assert_eq!(ebm_checked_shl(1u32, 32), None);
+        assert_eq!(ebm_checked_shl(1i16, 100), None);
+
+        // Test valid right shifts up to width - 1
+        assert_eq!(ebm_checked_shr(0x80u8, 7), Some(1u8));
+        assert_eq!(ebm_checked_shr(-16i32, 2), Some(-4i32));
+
+        // Test right shifts exactly at and beyond the bit width
+        assert_eq!(ebm_checked_shr(0x80u8, 8), None);
+        assert_eq!(ebm_checked_shr(u128::MAX, 128), None);
+    }
+
+    // Test saturating shifts clamp to the fill value on over-shift
+    #[test]
+    fn test_ebm_saturating_shifts() {
+        use bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_shift::*;
+
+        // Test left shifts within range behave like the normal shift
+        assert_eq!(ebm_shl_saturating(1u8, 7), 0x80u8);
+        assert_eq!(ebm_shl_saturating(0x0Fu16, 4), 0xF0u16);
+
+        // Test left shifts at and beyond the width saturate to zero
+        assert_eq!(ebm_shl_saturating(1u8, 8), 0u8);
+        assert_eq!(ebm_shl_saturating(u64::MAX, 200), 0u64);
+        assert_eq!(ebm_shl_saturating(-1i32, 32), 0i32);
+
+        // Test right shifts within range behave like the normal shift
+        assert_eq!(ebm_shr_saturating(0x80u8, 7), 1u8);
+        assert_eq!(ebm_shr_saturating(-64i8, 3), -8i8);
+
+        // Test right shifts at and beyond the width saturate to the fill value
+        assert_eq!(ebm_shr_saturating(0xFFu8, 8), 0u8);
+        assert_eq!(ebm_shr_saturating(i16::MAX, 16), 0i16);
+        assert_eq!(ebm_shr_saturating(-128i8, 8), -1i8);
+        assert_eq!(ebm_shr_saturating(i64::MIN, 1000), -1i64);
+    }
+
+    // Test byte histogram tallies every byte value
+    #[test]
+    fn test_ebm_byte_histogram() {
+        use bits::analysis::*;
+
+        // Test a uniform buffer yields equal counts in every bucket
+        let uniform: Vec<u8> = (0..512u32).map(|i| i as u8).collect();
+        let histogram = ebm_byte_histogram(&uniform);
+        assert!(histogram.iter().all(|&count| count == 2));
+
+        // Test an all-zero buffer puts everything in bucket 0
+        let histogram = ebm_byte_histogram(&[0u8; 100]);
+        assert_eq!(histogram[0], 100);
+        assert_eq!(histogram[1..].iter().sum::<u32>(), 0);
+
+        // Test the empty buffer leaves every bucket empty
+        assert_eq!(ebm_byte_histogram(&[]), [0u32; 256]);
+    }
+
+    // Test Shannon entropy on degenerate and uniform distributions
+    #[test]
+    fn test_ebm_shannon_entropy() {
+        use bits::analysis::*;
+
+        // Test the empty slice returns 0.0
+        assert_eq!(ebm_shannon_entropy(&[]), 0.0);
+
+        // Test an all-one-byte buffer has no entropy
+        assert_eq!(ebm_shannon_entropy(&[0xAAu8; 1000]), 0.0);
+
+        // Test a perfectly uniform distribution reaches 8 bits per byte
+        let uniform: Vec<u8> = (0..1024u32).map(|i| i as u8).collect();
+        assert!((ebm_shannon_entropy(&uniform) - 8.0).abs() < 1e-12);
+
+        // Test two equally likely bytes give exactly one bit
+        assert!((ebm_shannon_entropy(&[3, 9, 3, 9]) - 1.0).abs() < 1e-12);
+    }
+
+    // Test single-bit set, clear and test primitives
+    #[test]
+    fn test_ebm_single_bit_primitives() {
+        use bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_and::*;
+        use bits::bit_operations::bitwise_logic::bitwise_logic_advanced::bitwise_logic_or::*;
+
+        // Test setting bits
+        assert_eq!(ebm_set_bit(0u8, 3), 0b0000_1000u8);
+        assert_eq!(ebm_set_bit(0u64, 63), 1u64 << 63);
+        assert_eq!(ebm_set_bit(0i8, 7), i8::MIN);
+
+        // Test clearing bits
+        assert_eq!(ebm_clear_bit(0xFFu8, 0), 0xFEu8);
+        assert_eq!(ebm_clear_bit(-1i8, 7), i8::MAX);
+
+        // Test reading bits
+        assert!(ebm_test_bit(0b0100u8, 2));
+        assert!(!ebm_test_bit(0b0100u8, 3));
+        assert!(ebm_test_bit(u128::MAX, 127));
+    }
+
+    // Test packed booleans grow across word boundaries and iterate in order
+    #[test]
+    fn test_packed_bools() {
+        use bits::bitset::PackedBools;
+
+        // Test an empty sequence
+        let mut flags = PackedBools::new();
+        assert!(flags.is_empty());
+        assert_eq!(flags.get(0), None);
+
+        // Test pushing across several 64-bit word boundaries
+        let pattern = |i: usize| i % 3 == 2 || i == 64 || i == 127;
+        for i in 0..200 {
+            flags.push(pattern(i));
+        }
+        assert_eq!(flags.len(), 200);
+        for i in 0..200 {
+            assert_eq!(flags.get(i), Some(pattern(i)));
+        }
+        assert_eq!(flags.get(200), None);
+
+        // Test overwriting flags on both sides of a word boundary
+        flags.set(63, true);
+        flags.set(64, false);
+        assert_eq!(flags.get(63), Some(true));
+        assert_eq!(flags.get(64), Some(false));
+
+        // Test iteration yields every flag in order
+        let collected: Vec<bool> = flags.iter().collect();
+        assert_eq!(collected.len(), 200);
+        for (i, &flag) in collected.iter().enumerate() {
+            let expected = match i {
+                63 => true,
+                64 => false,
+                _ => pattern(i),
+            };
+            assert_eq!(flag, expected);
+        }
+    }
+
+    // Test longest runs of ones and zeros
+    #[test]
+    fn test_ebm_longest_runs() {
+        use bits::analysis::*;
+
+        // Test the documented example pattern
+        assert_eq!(ebm_longest_run_ones(0b0111_0011u8), 3);
+        assert_eq!(ebm_longest_run_zeros(0b0111_0011u8), 2);
+
+        // Test the all-zero edge case
+        assert_eq!(ebm_longest_run_ones(0u32), 0);
+        assert_eq!(ebm_longest_run_zeros(0u32), 32);
+
+        // Test the all-one edge case
+        assert_eq!(ebm_longest_run_ones(u64::MAX), 64);
+        assert_eq!(ebm_longest_run_zeros(u64::MAX), 0);
+        assert_eq!(ebm_longest_run_ones(-1i8), 8);
+
+        // Test runs touching the most significant bit
+        assert_eq!(ebm_longest_run_ones(0xF000_0001u32), 4);
+        assert_eq!(ebm_longest_run_zeros(0x0000_00FFu32), 24);
+    }
+
+    // Test bit transition counting
+    #[test]
+    fn test_ebm_count_transitions() {
+        use bits::analysis::*;
+
+        // Test alternating and constant patterns
+        assert_eq!(ebm_count_transitions(0b1010_1010u8), 7);
+        assert_eq!(ebm_count_transitions(0x00u8), 0);
+        assert_eq!(ebm_count_transitions(0xFFu8), 0);
+
+        // Test a single edge in the middle of the value
+        assert_eq!(ebm_count_transitions(0x0Fu8), 1);
+        assert_eq!(ebm_count_transitions(0x8000_0000u32), 1);
+
+        // Test signed types match the unsigned bit pattern
+        assert_eq!(ebm_count_transitions(-86i8), ebm_count_transitions(0xAAu8));
+        assert_eq!(ebm_count_transitions(-1i64), 0);
+        assert_eq!(ebm_count_transitions(0x5555_5555_5555_5555u64), 63);
+    }
+
+    // Test wrapping increment and decrement for cyclic counters
+    #[test]
+    fn test_ebm_wrapping_inc_dec() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test ordinary steps
+        assert_eq!(ebm_wrapping_inc(5u8), 6u8);
+        assert_eq!(ebm_wrapping_dec(5u8), 4u8);
+
+        // Test wrapping at the unsigned boundaries
+        assert_eq!(ebm_wrapping_inc(u8::MAX), 0u8);
+        assert_eq!(ebm_wrapping_dec(0u8), u8::MAX);
+        assert_eq!(ebm_wrapping_inc(u128::MAX), 0u128);
+
+        // Test wrapping at the signed boundaries
+        assert_eq!(ebm_wrapping_inc(i16::MAX), i16::MIN);
+        assert_eq!(ebm_wrapping_dec(i16::MIN), i16::MAX);
+    }
+
+    // Test power-of-two modulo against the real modulo
+    #[test]
+    fn test_ebm_mod_pow2() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_mod::*;
+
+        // Test several power-of-two moduli against the % operator
+        for shift in 0..32 {
+            let modulus = 1u32 << shift;
+            for value in [0u32, 1, 7, 13, 255, 1000, 65_537, u32::MAX] {
+                assert_eq!(ebm_mod_pow2(value, modulus), value % modulus);
+            }
+        }
+
+        // Test other widths
+        assert_eq!(ebm_mod_pow2(255u8, 16u8), 255u8 % 16);
+        assert_eq!(ebm_mod_pow2(100i32, 64i32), 100i32 % 64);
+        assert_eq!(ebm_mod_pow2(u128::MAX, 1u128 << 100), u128::MAX % (1u128 << 100));
+    }
+
+    // Test rotation within a narrow subword
+    #[test]
+    fn test_ebm_rotate_subword() {
+        use bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_rotate::*;
+
+        // Test the 7-bit field rotation from the cipher use case
+        assert_eq!(ebm_rotate_subword(0b1000000u8, 7, 1), 0b0000001u8);
+        assert_eq!(ebm_rotate_subword(0b1010101u8, 7, 7), 0b1010101u8);
+
+        // Test that a full-width subword matches the native rotate
+        assert_eq!(ebm_rotate_subword(0x1234u16, 16, 4), 0x1234u16.rotate_left(4));
+        assert_eq!(ebm_rotate_subword(i8::MIN, 8, 1), 1i8);
+
+        // Test that bits above the subword are cleared
+        assert_eq!(ebm_rotate_subword(0xF1u8, 4, 1), 0b0010u8);
+        assert_eq!(ebm_rotate_subword(-1i32, 12, 5), 0xFFFi32);
+
+        // Test amounts larger than the subword wrap around
+        assert_eq!(ebm_rotate_subword(0b011u8, 3, 5), 0b101u8);
+        assert_eq!(ebm_rotate_subword(0b011u8, 3, 0), 0b011u8);
+    }
+
+    // Test lookup-table byte reversal against the generic reverse
+    #[test]
+    fn test_ebm_reverse_bits_u8() {
+        use bits::bit_manipulation::*;
+
+        // Test the table against the generic reverse for all 256 byte values
+        for value in 0..=255u8 {
+            assert_eq!(ebm_reverse_bits_u8(value), ebm_reverse_bits(value));
+        }
+
+        // Test the generic reverse on wider types
+        assert_eq!(ebm_reverse_bits(1u32), 0x8000_0000u32);
+        assert_eq!(ebm_reverse_bits(1i8), i8::MIN);
+
+        // Test the in-place slice reversal
+        let mut buffer = [0x01u8, 0x80, 0x0F, 0xCA];
+        ebm_reverse_bits_slice(&mut buffer);
+        assert_eq!(buffer, [0x80, 0x01, 0xF0, 0x53]);
+    }
+
+    // Test Hamming weight comparison predicates
+    #[test]
+    fn test_ebm_bit_count_comparisons() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+
+        // Test strict ordering in both directions
+        assert!(ebm_has_more_bits(0b0111u8, 0b1001u8));
+        assert!(!ebm_has_more_bits(0b1001u8, 0b0111u8));
+        assert!(ebm_has_more_bits(-1i8, i8::MAX));
+
+        // Test equal weights are neither greater nor different
+        assert!(!ebm_has_more_bits(0b0011u16, 0b1100u16));
+        assert!(ebm_same_bit_count(0b0011u16, 0b1100u16));
+        assert!(ebm_same_bit_count(0u64, 0u64));
+
+        // Test differing weights are not the same
+        assert!(!ebm_same_bit_count(u32::MAX, u32::MAX - 1));
+    }
+
+    // Test canonical bit patterns cover every bit position for every width
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_ebm_bit_patterns() {
+        use bits::testing::*;
+
+        // Test the u8 set contains the documented patterns
+        let patterns = ebm_bit_patterns::<u8>();
+        for expected in [0x00u8, 0xFF, 0xAA, 0x55, 0x0F, 0xF0, 0x7F] {
+            assert!(patterns.contains(&expected));
+        }
+
+        // Test walking ones and zeros reach every bit position
+        let patterns = ebm_bit_patterns::<u128>();
+        for index in 0..128 {
+            assert!(patterns.contains(&(1u128 << index)));
+            assert!(patterns.contains(&!(1u128 << index)));
+        }
+
+        // Test signed types include both extremes and no duplicates
+        let patterns = ebm_bit_patterns::<i16>();
+        assert!(patterns.contains(&i16::MIN));
+        assert!(patterns.contains(&i16::MAX));
+        for (index, value) in patterns.iter().enumerate() {
+            assert!(!patterns[index + 1..].contains(value));
+        }
+    }
+
+    // Test two's-complement negation for signed and unsigned types
+    #[test]
+    fn test_ebm_negate() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test unsigned values negate to the additive inverse
+        assert_eq!(ebm_negate(1u8), 255u8);
+        assert_eq!(ebm_negate(0u8), 0u8);
+        assert_eq!(ebm_negate(200u8).wrapping_add(200u8), 0u8);
+
+        // Test signed values negate as usual
+        assert_eq!(ebm_negate(-5i8), 5i8);
+        assert_eq!(ebm_negate(5i64), -5i64);
+
+        // Test the signed minimum wraps to itself
+        assert_eq!(ebm_negate(i8::MIN), i8::MIN);
+    }
+
+    // Test masked Hamming distance over byte slices
+    #[test]
+    fn test_ebm_masked_hamming() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+
+        // Test a partial mask only counts the selected positions
+        assert_eq!(ebm_masked_hamming(&[0xFF, 0xFF], &[0x00, 0x00], &[0x0F, 0x80]), 5);
+
+        // Test a full mask is the plain Hamming distance
+        assert_eq!(ebm_masked_hamming(&[0xAA, 0x01], &[0x55, 0x01], &[0xFF, 0xFF]), 8);
+
+        // Test an empty mask and empty slices give zero
+        assert_eq!(ebm_masked_hamming(&[0xAA], &[0x55], &[0x00]), 0);
+        assert_eq!(ebm_masked_hamming(&[], &[], &[]), 0);
+    }
+
+    // Test masked Hamming distance rejects slices of different lengths
+    #[test]
+    #[should_panic]
+    fn test_ebm_masked_hamming_length_mismatch() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+        ebm_masked_hamming(&[0x00, 0x01], &[0x00, 0x01], &[0xFF]);
+    }
+
+    // Test branchless min, max and clamp across signed and unsigned types
+    #[test]
+    fn test_ebm_min_max_clamp() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test min and max on both orderings and equal values
+        assert_eq!(ebm_min(3u8, 7u8), 3u8);
+        assert_eq!(ebm_min(7u8, 3u8), 3u8);
+        assert_eq!(ebm_max(-4i32, 2i32), 2i32);
+        assert_eq!(ebm_max(i64::MIN, i64::MAX), i64::MAX);
+        assert_eq!(ebm_min(5u128, 5u128), 5u128);
+
+        // Test clamping values below, within and above an unsigned range
+        assert_eq!(ebm_clamp(5u16, 10u16, 20u16), 10u16);
+        assert_eq!(ebm_clamp(15u16, 10u16, 20u16), 15u16);
+        assert_eq!(ebm_clamp(25u16, 10u16, 20u16), 20u16);
+
+        // Test clamping values below, within and above a signed range
+        assert_eq!(ebm_clamp(-7i8, -5i8, 5i8), -5i8);
+        assert_eq!(ebm_clamp(3i8, -5i8, 5i8), 3i8);
+        assert_eq!(ebm_clamp(i8::MAX, -5i8, 5i8), 5i8);
+    }
+
+    // Test expanding bits to byte masks and packing them back
+    #[test]
+    fn test_ebm_bits_to_bytes_round_trip() {
+        use bits::bit_manipulation::*;
+
+        // Test the expansion order is least significant bit first
+        assert_eq!(ebm_bits_to_bytes(0b0000_0101u8), vec![0xFF, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(ebm_bits_to_bytes(0u32).len(), 32);
+
+        // Test the round trip for every u8 value
+        for value in 0..=255u8 {
+            assert_eq!(ebm_bytes_to_bits::<u8>(&ebm_bits_to_bytes(value)), value);
+        }
+
+        // Test only the high bit of each byte is packed
+        assert_eq!(ebm_bytes_to_bits::<u16>(&[0x80, 0x7F, 0xC0]), 0b101u16);
+    }
+
+    // Test rotations are identities for zero and whole-width amounts on every width
+    #[test]
+    fn test_ebm_rotate_identities_and_usize() {
+        use bits::bit_operations::bitwise_shifting::bitwise_shifting::*;
+
+        // Test a zero amount is the identity instead of overflowing the complementary shift
+        assert_eq!(ebm_left_rotate(0x5Au8, 0u8), 0x5Au8);
+        assert_eq!(ebm_right_rotate(0x1234u16, 0u32), 0x1234u16);
+
+        // Test usize with amounts derived from the platform width
+        let value: usize = 0x1234_5678;
+        assert_eq!(ebm_left_rotate(value, 0u32), value);
+        assert_eq!(ebm_left_rotate(value, usize::BITS), value);
+        assert_eq!(ebm_left_rotate(value, 3 * usize::BITS), value);
+        assert_eq!(ebm_right_rotate(value, 2 * usize::BITS), value);
+        assert_eq!(ebm_left_rotate(value, 1u32), value.rotate_left(1));
+        assert_eq!(ebm_left_rotate(1usize, usize::BITS - 1), 1usize << (usize::BITS - 1));
+        assert_eq!(ebm_right_rotate(1usize, 1u32), 1usize << (usize::BITS - 1));
+        assert_eq!(ebm_left_rotate(value, usize::BITS + 5), value.rotate_left(5));
+
+        // Test isize and other signed types rotate the raw bit pattern
+        assert_eq!(ebm_left_rotate(isize::MIN, 1u32), 1isize);
+        assert_eq!(ebm_left_rotate(i8::MIN, 1u8), 1i8);
+        assert_eq!(ebm_right_rotate(1i32, 1u32), i32::MIN);
+
+        // Test the result matches std on every amount for a 64-bit value
+        let wide = 0x0123_4567_89AB_CDEFu64;
+        for amount in 0..200u32 {
+            assert_eq!(ebm_left_rotate(wide, amount), wide.rotate_left(amount));
+            assert_eq!(ebm_right_rotate(wide, amount), wide.rotate_right(amount));
+        }
+    }
+
+    // Test bit field extraction, insertion and low masks
+    #[test]
+    fn test_ebm_bit_fields() {
+        use bits::bit_manipulation::*;
+
+        // Test low masks including the full-width edge case
+        assert_eq!(ebm_low_mask::<u8>(0), 0u8);
+        assert_eq!(ebm_low_mask::<u8>(3), 0b111u8);
+        assert_eq!(ebm_low_mask::<u8>(8), 0xFFu8);
+        assert_eq!(ebm_low_mask::<i8>(7), i8::MAX);
+
+        // Test extracting fields
+        assert_eq!(ebm_extract_bits(0b1101_0110u8, 2, 3), 0b101u8);
+        assert_eq!(ebm_extract_bits(-1i16, 12, 4), 0xFi16);
+        assert_eq!(ebm_extract_bits(0xDEAD_BEEFu32, 0, 32), 0xDEAD_BEEFu32);
+
+        // Test inserting fields leaves the other bits untouched
+        assert_eq!(ebm_insert_bits(0xFFu8, 0b010, 2, 3), 0b1110_1011u8);
+        assert_eq!(ebm_insert_bits(0u16, 0xFFF, 4, 4), 0x00F0u16);
+    }
+
+    // Test the generated bit field struct reads back what was written
+    #[test]
+    fn test_define_bitfield() {
+        crate::define_bitfield! {
+            struct Status(u8) {
+                mode, set_mode: 0, 3;
+                enabled, set_enabled: 3, 1;
+                priority, set_priority: 4, 4;
+            }
+        }
+
+        // Test setting the mode and reading it back
+        let mut status = Status::from_raw(0b1010_1000);
+        status.set_mode(5);
+        assert_eq!(status.mode(), 5);
+
+        // Test unrelated fields are untouched
+        assert_eq!(status.enabled(), 1);
+        assert_eq!(status.priority(), 0b1010);
+        assert_eq!(status.raw(), 0b1010_1101);
+
+        // Test oversized values are truncated to the field
+        status.set_enabled(0);
+        status.set_mode(0xFF);
+        assert_eq!(status.mode(), 0b111);
+        assert_eq!(status.enabled(), 0);
+        assert_eq!(status.priority(), 0b1010);
+    }
+
+    // Test overflow-checked left shift multiplication
+    #[test]
+    fn test_ebm_checked_shl_mul() {
+        use bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_shift::*;
+
+        // Test the documented unsigned cases
+        assert_eq!(ebm_checked_shl_mul(0x40u8, 2), None);
+        assert_eq!(ebm_checked_shl_mul(0x10u8, 2), Some(0x40u8));
+        assert_eq!(ebm_checked_shl_mul(1u32, 31), Some(1u32 << 31));
+        assert_eq!(ebm_checked_shl_mul(3u32, 31), None);
+
+        // Test signed values must keep their sign
+        assert_eq!(ebm_checked_shl_mul(0x20i8, 1), Some(0x40i8));
+        assert_eq!(ebm_checked_shl_mul(0x40i8, 1), None);
+        assert_eq!(ebm_checked_shl_mul(-64i8, 1), Some(-128i8));
+        assert_eq!(ebm_checked_shl_mul(-65i8, 1), None);
+
+        // Test amounts at or beyond the width
+        assert_eq!(ebm_checked_shl_mul(0u16, 16), Some(0u16));
+        assert_eq!(ebm_checked_shl_mul(1u16, 16), None);
+    }
+
+    // Test integer powers by squaring with wrapping and checked overflow
+    #[test]
+    fn test_ebm_pow() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test wrapping powers against the standard library
+        assert_eq!(ebm_pow(3u32, 4), 81u32);
+        assert_eq!(ebm_pow(7u64, 0), 1u64);
+        assert_eq!(ebm_pow(-2i32, 3), -8i32);
+        assert_eq!(ebm_pow(3u8, 7), 3u8.wrapping_pow(7));
+        assert_eq!(ebm_pow(2u8, 8), 0u8);
+
+        // Test checked powers that fit
+        assert_eq!(ebm_checked_pow(3u32, 4), Some(81u32));
+        assert_eq!(ebm_checked_pow(2u8, 7), Some(128u8));
+        assert_eq!(ebm_checked_pow(-2i8, 7), Some(-128i8));
+        assert_eq!(ebm_checked_pow(16u8, 1), Some(16u8));
+
+        // Test checked powers that overflow
+        assert_eq!(ebm_checked_pow(2u8, 8), None);
+        assert_eq!(ebm_checked_pow(10u32, 10), None);
+        assert_eq!(ebm_checked_pow(2i8, 7), None);
+    }
+
+    // Test the XOR (LRC) checksum
+    #[test]
+    fn test_ebm_xor_checksum() {
+        use bits::bit_operations::bitwise_logic::bitwise_logic_advanced::other_related::*;
+
+        // Test the empty slice
+        assert_eq!(ebm_xor_checksum(&[]), 0x00);
+
+        // Test known small messages
+        assert_eq!(ebm_xor_checksum(b"Hello"), 0x42);
+        assert_eq!(ebm_xor_checksum(&[0x01, 0x02, 0x03]), 0x00);
+        assert_eq!(ebm_xor_checksum(&[0xA5]), 0xA5);
+
+        // Test a frame with its checksum appended folds to zero
+        let mut frame = b"Eidolon".to_vec();
+        frame.push(ebm_xor_checksum(&frame));
+        assert_eq!(ebm_xor_checksum(&frame), 0x00);
+    }
+
+    // Test Fletcher-16 against the standard test vectors
+    #[test]
+    fn test_ebm_fletcher16() {
+        use bits::analysis::*;
+
+        // Test the empty input
+        assert_eq!(ebm_fletcher16(&[]), 0x0000);
+
+        // Test the standard test vectors
+        assert_eq!(ebm_fletcher16(b"abcde"), 0xC8F0);
+        assert_eq!(ebm_fletcher16(b"abcdef"), 0x2057);
+        assert_eq!(ebm_fletcher16(b"abcdefgh"), 0x0627);
+
+        // Test a long buffer of maximal bytes keeps both sums reduced
+        assert_eq!(ebm_fletcher16(&[0xFFu8; 4096]), 0x0000);
+    }
+
+    // Test swapping two bit positions
+    #[test]
+    fn test_ebm_swap_bits() {
+        use bits::bit_manipulation::*;
+
+        // Test moving bit 0 to bit 7
+        assert_eq!(ebm_swap_bits(0x01u8, 0, 7), 0x80u8);
+        assert_eq!(ebm_swap_bits(0x80u8, 0, 7), 0x01u8);
+
+        // Test that equal bits leave the value unchanged
+        assert_eq!(ebm_swap_bits(0x81u8, 0, 7), 0x81u8);
+        assert_eq!(ebm_swap_bits(0b0110u8, 1, 2), 0b0110u8);
+
+        // Test the i == j no-op case
+        assert_eq!(ebm_swap_bits(0x01u8, 0, 0), 0x01u8);
+        assert_eq!(ebm_swap_bits(0x5Au8, 3, 3), 0x5Au8);
+
+        // Test other widths, including the sign bit
+        assert_eq!(ebm_swap_bits(0x0001u16, 0, 15), 0x8000u16);
+        assert_eq!(ebm_swap_bits(1i32, 0, 31), i32::MIN);
+    }
+
+    // Test applying bit permutations
+    #[test]
+    fn test_ebm_permute_bits() {
+        use bits::bit_manipulation::*;
+
+        // Test that the identity permutation returns the input
+        let identity: Vec<u32> = (0..16).collect();
+        for a in [0x0000u16, 0x1234, 0xBEEF, 0xFFFF] {
+            assert_eq!(ebm_permute_bits(a, &identity), a);
+        }
+
+        // Test that the full reversal permutation matches ebm_reverse_bits
+        let reversal: Vec<u32> = (0..32).rev().collect();
+        for a in [0u32, 1, 0x1234_5678, 0xDEAD_BEEF, u32::MAX] {
+            assert_eq!(ebm_permute_bits(a, &reversal), ebm_reverse_bits(a));
+        }
+
+        // Test a short table that gathers bits into the low positions
+        assert_eq!(ebm_permute_bits(0b0000_0110u8, &[1, 2]), 0b11u8);
+
+        // Test a table that duplicates a source bit
+        assert_eq!(ebm_permute_bits(0b1000_0000u8, &[7, 7, 0]), 0b011u8);
+    }
+
+    // Test the de Bruijn trailing zero scan
+    #[test]
+    fn test_ebm_debruijn_trailing_zeros_u32() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_trailing::*;
+
+        // Test every single-bit input against the intrinsic
+        for n in 0..32 {
+            let a = 1u32 << n;
+            assert_eq!(ebm_debruijn_trailing_zeros_u32(a), a.trailing_zeros());
+        }
+
+        // Test that higher set bits do not affect the result
+        for a in [0b1011_0000u32, 0xFFFF_FFFF, 0x8000_0001, 0xDEAD_BEEF, 0x1234_5600] {
+            assert_eq!(ebm_debruijn_trailing_zeros_u32(a), a.trailing_zeros());
+        }
+
+        // Test the zero input
+        assert_eq!(ebm_debruijn_trailing_zeros_u32(0), 32);
+    }
+
+    // Test counting set bits in the operand type
+    #[test]
+    fn test_ebm_count_ones_as() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+
+        // Test unsigned widths
+        assert_eq!(ebm_count_ones_as(0xF0u8), 4u8);
+        assert_eq!(ebm_count_ones_as(0xFFFFu16), 16u16);
+        assert_eq!(ebm_count_ones_as(0x0101_0101u32), 4u32);
+        assert_eq!(ebm_count_ones_as(u64::MAX), 64u64);
+        assert_eq!(ebm_count_ones_as(u128::MAX), 128u128);
+        assert_eq!(ebm_count_ones_as(0usize), 0usize);
+
+        // Test signed widths, where the full count still fits
+        assert_eq!(ebm_count_ones_as(-1i8), 8i8);
+        assert_eq!(ebm_count_ones_as(i16::MIN), 1i16);
+        assert_eq!(ebm_count_ones_as(-1i128), 128i128);
+
+        // Test that the result composes in arithmetic without casts
+        assert_eq!(ebm_count_ones_as(0b1011u8) * 2, 6u8);
+    }
+
+    // Test the 8x8 bit matrix transpose
+    #[test]
+    fn test_ebm_transpose_8x8() {
+        use bits::matrix::*;
+
+        // Test that a full row becomes a full column
+        assert_eq!(ebm_transpose_8x8([0xFF, 0, 0, 0, 0, 0, 0, 0]), [0x01; 8]);
+
+        // Test a known tile against an element-by-element transpose
+        let tile = [0x18, 0x3C, 0x7E, 0xDB, 0xFF, 0x24, 0x5A, 0xA5];
+        let mut expected = [0u8; 8];
+        for (r, row) in tile.iter().enumerate() {
+            for (c, out) in expected.iter_mut().enumerate() {
+                *out |= ((row >> c) & 1) << r;
+            }
+        }
+        assert_eq!(ebm_transpose_8x8(tile), expected);
+
+        // Test that transposing twice is the identity
+        assert_eq!(ebm_transpose_8x8(ebm_transpose_8x8(tile)), tile);
+
+        // Test that the diagonal is a fixed point
+        let diagonal = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80];
+        assert_eq!(ebm_transpose_8x8(diagonal), diagonal);
+    }
+
+    // Test the 64x64 bit matrix transpose
+    #[test]
+    fn test_ebm_transpose_bitmatrix() {
+        use bits::matrix::*;
+
+        // Test that a single bit moves to the mirrored position
+        let mut rows = [0u64; 64];
+        rows[3] = 1 << 10;
+        ebm_transpose_bitmatrix(&mut rows);
+        let mut expected = [0u64; 64];
+        expected[10] = 1 << 3;
+        assert_eq!(rows, expected);
+
+        // Test a dense matrix against an element-by-element transpose
+        let mut original = [0u64; 64];
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        for row in original.iter_mut() {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            *row = state;
+        }
+        let mut expected = [0u64; 64];
+        for (r, row) in original.iter().enumerate() {
+            for (c, out) in expected.iter_mut().enumerate() {
+                *out |= ((row >> c) & 1) << r;
+            }
+        }
+        let mut rows = original;
+        ebm_transpose_bitmatrix(&mut rows);
+        assert_eq!(rows, expected);
+
+        // Test the round-trip identity
+        ebm_transpose_bitmatrix(&mut rows);
+        assert_eq!(rows, original);
+    }
+
+    // Test the floor and ceiling integer square roots
+    #[test]
+    fn test_ebm_isqrt() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test the ceiling root of a non-square
+        assert_eq!(ebm_isqrt(17u32), 4);
+        assert_eq!(ebm_isqrt_ceil(17u32), 5);
+
+        // Test that perfect squares return their exact root
+        for root in 0u32..1000 {
+            assert_eq!(ebm_isqrt(root * root), root);
+            assert_eq!(ebm_isqrt_ceil(root * root), root);
+        }
+
+        // Test every u8 and i8 against the defining inequalities
+        for a in 0u8..=255 {
+            let floor = ebm_isqrt(a) as u32;
+            let ceil = ebm_isqrt_ceil(a) as u32;
+            assert!(floor * floor <= a as u32 && (floor + 1) * (floor + 1) > a as u32);
+            assert!(ceil * ceil >= a as u32 && (ceil == 0 || (ceil - 1) * (ceil - 1) < a as u32));
+        }
+        for a in 0i8..=127 {
+            let floor = ebm_isqrt(a) as i32;
+            assert!(floor * floor <= a as i32 && (floor + 1) * (floor + 1) > a as i32);
+        }
+
+        // Test the extremes of wider types
+        assert_eq!(ebm_isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(ebm_isqrt_ceil(u64::MAX), 1u64 << 32);
+        assert_eq!(ebm_isqrt(i32::MAX), 46340);
+        assert_eq!(ebm_isqrt(u128::MAX), u64::MAX as u128);
+    }
+
+    // Test the rotate-XOR-multiply hash mixer
+    #[test]
+    fn test_ebm_mix() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test that the mixer is deterministic and order sensitive
+        assert_eq!(ebm_mix(1u64, 2u64), ebm_mix(1u64, 2u64));
+        assert_ne!(ebm_mix(1u64, 2u64), ebm_mix(2u64, 1u64));
+        assert_ne!(ebm_mix(5u32, 5u32), 0);
+
+        // Test avalanche: flipping any single input bit flips about half of the output bits
+        for (a, b) in [(0u64, 0u64), (0x0123_4567_89AB_CDEF, 42), (u64::MAX, 7)] {
+            let base = ebm_mix(a, b);
+            let mut total = 0;
+            for bit in 0..64 {
+                let flipped_a = (ebm_mix(a ^ (1 << bit), b) ^ base).count_ones();
+                let flipped_b = (ebm_mix(a, b ^ (1 << bit)) ^ base).count_ones();
+                assert!(flipped_a >= 8 && flipped_b >= 8);
+                total += flipped_a + flipped_b;
+            }
+            let average = total as f64 / 128.0;
+            assert!((24.0..=40.0).contains(&average), "average flipped bits {}", average);
+        }
+
+        // Test that every width is supported
+        let _ = ebm_mix(1u8, 2u8);
+        let _ = ebm_mix(1i16, 2i16);
+        let _ = ebm_mix(1usize, 2usize);
+        assert_ne!(ebm_mix(1u128, 0u128), ebm_mix(0u128, 0u128));
+    }
+
+    // Test the binary search leading zero count
+    #[test]
+    fn test_ebm_clz_binary_search_u32() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_leading::*;
+
+        // Test every single-bit input against the intrinsic
+        for n in 0..32 {
+            let a = 1u32 << n;
+            assert_eq!(ebm_clz_binary_search_u32(a), a.leading_zeros());
+        }
+
+        // Test that lower set bits do not affect the result
+        for a in [0b1011u32, 0xFFFF_FFFF, 0x0000_FFFF, 0x00F0_0001, 0x1234_5678] {
+            assert_eq!(ebm_clz_binary_search_u32(a), a.leading_zeros());
+        }
+
+        // Test the zero input
+        assert_eq!(ebm_clz_binary_search_u32(0), 32);
+    }
+
+    // Test iterating over the runs of equal bits
+    #[test]
+    fn test_ebm_runs() {
+        use bits::analysis::*;
+
+        // Test the runs of a mixed byte
+        let runs: Vec<_> = ebm_runs(0b0011_1000u8).collect();
+        assert_eq!(runs, vec![(0, 3, false), (3, 3, true), (6, 2, false)]);
+
+        // Test single runs covering the whole value
+        assert_eq!(ebm_runs(0u16).collect::<Vec<_>>(), vec![(0, 16, false)]);
+        assert_eq!(ebm_runs(u32::MAX).collect::<Vec<_>>(), vec![(0, 32, true)]);
+
+        // Test a signed value whose top run must not extend past the width
+        assert_eq!(ebm_runs(-2i8).collect::<Vec<_>>(), vec![(0, 1, false), (1, 7, true)]);
+
+        // Test that the runs always cover the width and alternate
+        for a in [0x5555u16, 0x00FF, 0x8001, 0x1234] {
+            let runs: Vec<_> = ebm_runs(a).collect();
+            assert_eq!(runs.iter().map(|run| run.1).sum::<u32>(), 16);
+            assert!(runs.windows(2).all(|pair| pair[0].2 != pair[1].2));
+            assert_eq!(runs.len() as u32, ebm_count_transitions(a) + 1);
+        }
+    }
+
+    // Test the signed saturating left shift
+    #[test]
+    fn test_ebm_signed_shl_saturating() {
+        use bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_shift::*;
+
+        // Test exact shifts of positive and negative values
+        assert_eq!(ebm_signed_shl_saturating(0x10i8, 2), 0x40);
+        assert_eq!(ebm_signed_shl_saturating(-0x10i8, 3), -128);
+        assert_eq!(ebm_signed_shl_saturating(-3i16, 4), -48);
+
+        // Test positive values saturating at the maximum
+        assert_eq!(ebm_signed_shl_saturating(0x20i8, 2), i8::MAX);
+        assert_eq!(ebm_signed_shl_saturating(1i32, 31), i32::MAX);
+        assert_eq!(ebm_signed_shl_saturating(1i64, 200), i64::MAX);
+
+        // Test negative values saturating at the minimum
+        assert_eq!(ebm_signed_shl_saturating(-0x20i8, 3), i8::MIN);
+        assert_eq!(ebm_signed_shl_saturating(-1i32, 32), i32::MIN);
+
+        // Test that zero never saturates
+        assert_eq!(ebm_signed_shl_saturating(0i16, 100), 0);
+
+        // Test unsigned values saturating at the maximum
+        assert_eq!(ebm_signed_shl_saturating(0x81u8, 1), u8::MAX);
+    }
+
+    // Test the SWAR zero byte detection
+    #[test]
+    fn test_ebm_has_zero_byte() {
+        use bits::analysis::*;
+
+        // Test words with a zero byte at every position
+        for index in 0..8 {
+            let mut bytes = *b"abcdefgh";
+            bytes[index] = 0;
+            let word = u64::from_le_bytes(bytes);
+            assert!(ebm_has_zero_byte(word));
+            assert_eq!(ebm_zero_byte_index(word), Some(index as u32));
+        }
+
+        // Test words without a zero byte
+        for word in [u64::from_le_bytes(*b"abcdefgh"), u64::MAX, 0x8080_8080_8080_8080, 0x0101_0101_0101_0101] {
+            assert!(!ebm_has_zero_byte(word));
+            assert_eq!(ebm_zero_byte_index(word), None);
+        }
+
+        // Test that a 0x01 byte above a zero byte does not move the index
+        assert_eq!(ebm_zero_byte_index(0x0000_0000_0000_0100), Some(0));
+        assert_eq!(ebm_zero_byte_index(0xFFFF_FFFF_FFFF_0100), Some(0));
+
+        // Test the all-zero word
+        assert_eq!(ebm_zero_byte_index(0), Some(0));
+    }
+
+    // Test the SWAR byte search
+    #[test]
+    fn test_ebm_has_byte() {
+        use bits::analysis::*;
+
+        // Test finding the needle in each of the eight lanes
+        for index in 0..8 {
+            let mut bytes = *b"abcdefgh";
+            bytes[index] = b'z';
+            assert!(ebm_has_byte(u64::from_le_bytes(bytes), b'z'));
+        }
+
+        // Test a needle that is absent entirely
+        assert!(!ebm_has_byte(u64::from_le_bytes(*b"abcdefgh"), b'z'));
+        assert!(!ebm_has_byte(u64::MAX, 0x00));
+        assert!(!ebm_has_byte(0, 0xFF));
+
+        // Test the zero and 0xFF needles
+        assert!(ebm_has_byte(0xFF00_0000_0000_0000, 0xFF));
+        assert!(ebm_has_byte(0x1111_1111_0011_1111, 0x00));
+    }
+
+    // Test replicating a byte across all lanes
+    #[test]
+    fn test_ebm_broadcast_byte() {
+        use bits::bit_manipulation::*;
+
+        // Test the 64-bit broadcast
+        assert_eq!(ebm_broadcast_byte(0xAB), 0xABAB_ABAB_ABAB_ABAB);
+        assert_eq!(ebm_broadcast_byte(0x00), 0);
+        assert_eq!(ebm_broadcast_byte(0xFF), u64::MAX);
+
+        // Test that the generic form agrees with the 64-bit form
+        for b in [0x00u8, 0x01, 0x7F, 0x80, 0xAB, 0xFF] {
+            assert_eq!(ebm_broadcast::<u64>(b), ebm_broadcast_byte(b));
+        }
+
+        // Test other widths
+        assert_eq!(ebm_broadcast::<u8>(0x5A), 0x5A);
+        assert_eq!(ebm_broadcast::<u16>(0x12), 0x1212);
+        assert_eq!(ebm_broadcast::<u32>(0xAB), 0xABAB_ABAB);
+        assert_eq!(ebm_broadcast::<u128>(0x0F), 0x0F0F_0F0F_0F0F_0F0F_0F0F_0F0F_0F0F_0F0F);
+        assert_eq!(ebm_broadcast::<i16>(0xFF), -1);
+        assert_eq!(ebm_broadcast::<i32>(0x80), 0x8080_8080u32 as i32);
+    }
+
+    // Test the bit-reversed counter increment
+    #[test]
+    fn test_ebm_reverse_increment() {
+        use bits::bit_manipulation::*;
+
+        // Test the 3-bit sequence and its wrap-around
+        let mut sequence = vec![0u8];
+        for _ in 0..8 {
+            sequence.push(ebm_reverse_increment(*sequence.last().unwrap(), 3));
+        }
+        assert_eq!(sequence, vec![0, 4, 2, 6, 1, 5, 3, 7, 0]);
+
+        // Test that the counter matches reversing a normal counter
+        let mut counter = 0u16;
+        for index in 0u16..1024 {
+            assert_eq!(counter, index.reverse_bits() >> 6);
+            counter = ebm_reverse_increment(counter, 10);
+        }
+
+        // Test the full width of a signed type
+        assert_eq!(ebm_reverse_increment(0i8, 8), i8::MIN);
+        assert_eq!(ebm_reverse_increment(-1i8, 8), 0);
+
+        // Test that bits above the field are preserved
+        assert_eq!(ebm_reverse_increment(0xF0u8, 4), 0xF8);
+    }
+
+    // Test the 128-bit SWAR population count
+    #[test]
+    fn test_ebm_popcount_u128() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_population::*;
+
+        // Test the empty and the all-set case
+        assert_eq!(ebm_popcount_u128(0), 0);
+        assert_eq!(ebm_popcount_u128(u128::MAX), 128);
+
+        // Test pseudo-random values against the intrinsic
+        let mut state = 0x2545_F491_4F6C_DD1Du128;
+        for _ in 0..1000 {
+            state = state.wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645).wrapping_add(0x5851_F42D_4C95_7F2D);
+            assert_eq!(ebm_popcount_u128(state), state.count_ones());
+        }
+
+        // Test every single-bit value
+        for n in 0..128 {
+            assert_eq!(ebm_popcount_u128(1u128 << n), 1);
+        }
+    }
+
+    // Test multi-precision addition with carry propagation
+    #[test]
+    fn test_ebm_add_limbs() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_add::*;
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test the overflowing addition helper
+        assert_eq!(ebm_overflowing_add(200u8, 100u8), (44, true));
+        assert_eq!(ebm_overflowing_add(1u8, 2u8), (3, false));
+        assert_eq!(ebm_overflowing_add(127i8, 1i8), (-128, true));
+        assert_eq!(ebm_overflowing_add(-1i8, 1i8), (0, false));
+
+        // Test a carry that runs across every limb
+        let mut a = [u64::MAX, u64::MAX, u64::MAX, 0];
+        assert!(!ebm_add_limbs(&mut a, &[1, 0, 0, 0]));
+        assert_eq!(a, [0, 0, 0, 1]);
+
+        // Test the final carry-out of all ones plus one
+        let mut a = [u64::MAX; 3];
+        assert!(ebm_add_limbs(&mut a, &[1, 0, 0]));
+        assert_eq!(a, [0, 0, 0]);
+
+        // Test limbs that overflow while also receiving a carry
+        let mut a = [u64::MAX, u64::MAX];
+        assert!(ebm_add_limbs(&mut a, &[u64::MAX, u64::MAX]));
+        assert_eq!(a, [u64::MAX - 1, u64::MAX]);
+
+        // Test the agreement with u128 arithmetic
+        let (x, y) = (0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128, 0xFFFF_0000_FFFF_0000_FFFF_0000_FFFF_0000u128);
+        let mut a = [x as u64, (x >> 64) as u64];
+        let carry = ebm_add_limbs(&mut a, &[y as u64, (y >> 64) as u64]);
+        let (sum, overflow) = x.overflowing_add(y);
+        assert_eq!((a, carry), ([sum as u64, (sum >> 64) as u64], overflow));
+
+        // Test the empty number
+        assert!(!ebm_add_limbs(&mut [], &[]));
+    }
+
+    // Test multi-precision subtraction with borrow propagation
+    #[test]
+    fn test_ebm_sub_limbs() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_sub::*;
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test the overflowing subtraction helper
+        assert_eq!(ebm_overflowing_sub(0u8, 1u8), (255, true));
+        assert_eq!(ebm_overflowing_sub(5u8, 3u8), (2, false));
+        assert_eq!(ebm_overflowing_sub(-128i8, 1i8), (127, true));
+
+        // Test a borrow from the next limb without a final borrow
+        let mut a = [0, 1];
+        assert!(!ebm_sub_limbs(&mut a, &[1, 0]));
+        assert_eq!(a, [u64::MAX, 0]);
+
+        // Test a borrow that runs across every limb
+        let mut a = [0, 0, 0, 1];
+        assert!(!ebm_sub_limbs(&mut a, &[1, 0, 0, 0]));
+        assert_eq!(a, [u64::MAX, u64::MAX, u64::MAX, 0]);
+
+        // Test the final borrow of a negative difference
+        let mut a = [0, 0];
+        assert!(ebm_sub_limbs(&mut a, &[1, 0]));
+        assert_eq!(a, [u64::MAX, u64::MAX]);
+
+        // Test that subtraction undoes addition
+        let original = [0x0123_4567_89AB_CDEF, u64::MAX, 7];
+        let addend = [u64::MAX, 1, 0];
+        let mut a = original;
+        let carry = ebm_add_limbs(&mut a, &addend);
+        let borrow = ebm_sub_limbs(&mut a, &addend);
+        assert_eq!(a, original);
+        assert_eq!(carry, borrow);
+    }
+
+    // Test comparing multi-precision numbers
+    #[test]
+    fn test_ebm_cmp_limbs() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+        use std::cmp::Ordering;
+
+        // Test equal numbers
+        assert_eq!(ebm_cmp_limbs(&[5, 1], &[5, 1]), Ordering::Equal);
+        assert_eq!(ebm_cmp_limbs(&[], &[]), Ordering::Equal);
+
+        // Test numbers that differ only in the low limb
+        assert_eq!(ebm_cmp_limbs(&[5, 1, 9], &[7, 1, 9]), Ordering::Less);
+        assert_eq!(ebm_cmp_limbs(&[8, 1, 9], &[7, 1, 9]), Ordering::Greater);
+
+        // Test that the high limb outweighs the low limbs
+        assert_eq!(ebm_cmp_limbs(&[u64::MAX, 0], &[0, 1]), Ordering::Less);
+        assert_eq!(ebm_cmp_limbs(&[0, 2], &[u64::MAX, 1]), Ordering::Greater);
+
+        // Test the agreement with u128 ordering
+        for (x, y) in [(1u128 << 64, u64::MAX as u128), (3, 3), (0, u128::MAX)] {
+            let a = [x as u64, (x >> 64) as u64];
+            let b = [y as u64, (y >> 64) as u64];
+            assert_eq!(ebm_cmp_limbs(&a, &b), x.cmp(&y));
+        }
+    }
+
+    // Test shifting multi-precision numbers
+    #[test]
+    fn test_ebm_shl_limbs() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+        use bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_shift::*;
+
+        // Test the funnel shift helper
+        assert_eq!(ebm_funnel_shl(0x12u8, 0x34u8, 4), 0x23);
+        assert_eq!(ebm_funnel_shl(0x00u8, 0x80u8, 1), 0x01);
+        assert_eq!(ebm_funnel_shl(0xABu8, 0xCDu8, 0), 0xAB);
+        assert_eq!(ebm_funnel_shl(0i8, -128i8, 1), 1);
+
+        // Test whole-limb shifts and a remainder
+        let mut a = [1, 0];
+        ebm_shl_limbs(&mut a, 64);
+        assert_eq!(a, [0, 1]);
+        let mut a = [1, 0];
+        ebm_shl_limbs(&mut a, 65);
+        assert_eq!(a, [0, 2]);
+
+        // Test bits crossing a limb boundary
+        let mut a = [0x8000_0000_0000_0001, 0, 0];
+        ebm_shl_limbs(&mut a, 1);
+        assert_eq!(a, [2, 1, 0]);
+
+        // Test that shifting past the top clears the number
+        let mut a = [u64::MAX, u64::MAX];
+        ebm_shl_limbs(&mut a, 128);
+        assert_eq!(a, [0, 0]);
+
+        // Test the agreement with u128 shifts
+        let x = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210u128;
+        for amount in 0..128 {
+            let mut a = [x as u64, (x >> 64) as u64];
+            ebm_shl_limbs(&mut a, amount);
+            let shifted = x << amount;
+            assert_eq!(a, [shifted as u64, (shifted >> 64) as u64]);
+        }
+    }
+
+    // Test the single set bit predicate
+    #[test]
+    fn test_ebm_is_single_bit() {
+        use bits::bit_manipulation::*;
+
+        // Test single-bit inputs
+        for n in 0..16 {
+            assert!(ebm_is_single_bit(1u16 << n));
+        }
+        assert!(ebm_is_single_bit(i32::MIN));
+
+        // Test multi-bit inputs
+        assert!(!ebm_is_single_bit(0b0110u8));
+        assert!(!ebm_is_single_bit(u64::MAX));
+        assert!(!ebm_is_single_bit(-1i8));
+        assert!(!ebm_is_single_bit(0x8001u16));
+
+        // Test the zero input
+        assert!(!ebm_is_single_bit(0u8));
+        assert!(!ebm_is_single_bit(0i64));
+    }
+
+    // Test rounding up to arbitrary multiples
+    #[test]
+    fn test_ebm_round_up_to_multiple() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test rounding up to the next multiple
+        assert_eq!(ebm_round_up_to_multiple(10u32, 7u32), 14);
+        assert_eq!(ebm_round_up_to_multiple(1u32, 12u32), 12);
+        assert_eq!(ebm_round_up_to_multiple(25usize, 24usize), 48);
+
+        // Test values that already are a multiple
+        assert_eq!(ebm_round_up_to_multiple(14u32, 7u32), 14);
+        assert_eq!(ebm_round_up_to_multiple(0u32, 7u32), 0);
+        assert_eq!(ebm_round_up_to_multiple(9u8, 1u8), 9);
+
+        // Test results near the top of the type that the textbook formula would overflow on
+        assert_eq!(ebm_round_up_to_multiple(250u8, 5u8), 250);
+        assert_eq!(ebm_round_up_to_multiple(251u8, 3u8), 252);
+
+        // Test signed values
+        assert_eq!(ebm_round_up_to_multiple(100i16, 30i16), 120);
+    }
+
+    // Test the index of a one-hot bit
+    #[test]
+    fn test_ebm_log2_exact() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+
+        // Test all one-hot u16 values
+        for n in 0..16 {
+            assert_eq!(ebm_log2_exact(1u16 << n), n);
+        }
+
+        // Test other widths, including the sign bit
+        assert_eq!(ebm_log2_exact(1u128 << 100), 100);
+        assert_eq!(ebm_log2_exact(i8::MIN), 7);
+    }
+
+    // Test that multi-bit values violate the one-hot precondition
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "exactly one bit set")]
+    fn test_ebm_log2_exact_rejects_multiple_bits() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+
+        ebm_log2_exact(0b0110u16);
+    }
+
+    // Test the lowest set bit masks
+    #[test]
+    fn test_ebm_lowest_set_masks() {
+        use bits::bit_manipulation::*;
+
+        // Test the priority windows of a mixed value
+        assert_eq!(ebm_lowest_set_mask(0b0010_1000u8), 0b0000_1000);
+        assert_eq!(ebm_below_lowest_set(0b0010_1000u8), 0b0000_0111);
+
+        // Test a set bit 0, which has nothing below it
+        assert_eq!(ebm_lowest_set_mask(0b0000_0001u8), 0b0000_0001);
+        assert_eq!(ebm_below_lowest_set(0b0000_0001u8), 0);
+
+        // Test the zero input
+        assert_eq!(ebm_lowest_set_mask(0u8), 0);
+        assert_eq!(ebm_below_lowest_set(0u8), 0xFF);
+
+        // Test the sign bit of signed types
+        assert_eq!(ebm_lowest_set_mask(i16::MIN), i16::MIN);
+        assert_eq!(ebm_below_lowest_set(i16::MIN), i16::MAX);
+
+        // Test that both masks agree with the trailing zero count
+        for a in [0x0100u16, 0xFFF0, 0x8000, 0x1234] {
+            assert_eq!(ebm_lowest_set_mask(a), 1 << a.trailing_zeros());
+            assert_eq!(ebm_below_lowest_set(a), (1 << a.trailing_zeros()) - 1);
+        }
+    }
+
+    // Test spreading bits apart by a stride
+    #[test]
+    fn test_ebm_spread_bits() {
+        use bits::morton::*;
+
+        // Test stride-2 spreading
+        assert_eq!(ebm_spread_bits(0b111u8, 2), 0b01_0101);
+        assert_eq!(ebm_spread_bits(0xFFFFu32, 2), 0x5555_5555);
+
+        // Test stride 3 and the identity stride
+        assert_eq!(ebm_spread_bits(0b11u16, 3), 0b1001);
+        assert_eq!(ebm_spread_bits(0b1011u8, 1), 0b1011);
+
+        // Test that bits beyond the width are discarded
+        assert_eq!(ebm_spread_bits(0xFFu8, 4), 0b0001_0001);
+
+        // Test a 2D Morton code built from two spread coordinates
+        let (x, y) = (0b101u16, 0b011u16);
+        assert_eq!(ebm_spread_bits(x, 2) | (ebm_spread_bits(y, 2) << 1), 0b01_1011);
+    }
+
+    // Test gathering strided bits into contiguous bits
+    #[test]
+    fn test_ebm_gather_bits() {
+        use bits::morton::*;
+
+        // Test gathering the even and odd bits
+        assert_eq!(ebm_gather_bits(0b01_0101u8, 2), 0b111);
+        assert_eq!(ebm_gather_bits(0b10_1010u8 >> 1, 2), 0b111);
+        assert_eq!(ebm_gather_bits(0b1001u16, 3), 0b11);
+
+        // Test that bits between the strided positions are ignored
+        assert_eq!(ebm_gather_bits(0xFFu8, 2), 0x0F);
+
+        // Test that gather undoes spread for several strides
+        for stride in 1..=8 {
+            let fits = 64u32.div_ceil(stride);
+            for x in [0u64, 1, 0b1011, 0xDEAD_BEEF, u64::MAX] {
+                let x = if fits >= 64 { x } else { x & ((1 << fits) - 1) };
+                assert_eq!(ebm_gather_bits(ebm_spread_bits(x, stride), stride), x);
+            }
+        }
+    }
+
+    // Test the Fibonacci and Galois linear feedback shift registers
+    #[test]
+    fn test_lfsr() {
+        use bits::lfsr::*;
+
+        // Counts the steps until the register returns to its seed
+        fn period<T: bits::ebm_int::EbmInt>(mut lfsr: Lfsr<T>) -> u32 {
+            let seed = lfsr.state();
+            let mut steps = 1;
+            while lfsr.step() != seed {
+                steps += 1;
+            }
+            steps
+        }
+
+        // Test the full period of x^4 + x^3 + 1 in both modes
+        assert_eq!(period(Lfsr::new(LfsrMode::Galois, 4, 0b1100u8, 1)), 15);
+        assert_eq!(period(Lfsr::new(LfsrMode::Fibonacci, 4, 0b0011u8, 1)), 15);
+
+        // Test the full period of x^8 + x^6 + x^5 + x^4 + 1 in both modes
+        assert_eq!(period(Lfsr::new(LfsrMode::Galois, 8, 0xB8u8, 1)), 255);
+        assert_eq!(period(Lfsr::new(LfsrMode::Fibonacci, 8, 0x1Du8, 1)), 255);
+
+        // Test a register filling a signed type, which must shift logically
+        assert_eq!(period(Lfsr::new(LfsrMode::Galois, 8, 0xB8u8 as i8, -1)), 255);
+
+        // Test that a maximal-length register visits every non-zero state once
+        let mut lfsr = Lfsr::new(LfsrMode::Galois, 4, 0b1100u16, 1);
+        let mut states: Vec<u16> = (0..15).map(|_| lfsr.step()).collect();
+        states.sort();
+        assert_eq!(states, (1..16).collect::<Vec<u16>>());
+
+        // Test that the output bit is the low bit before the step
+        let mut lfsr = Lfsr::new(LfsrMode::Fibonacci, 4, 0b0011u8, 0b0101);
+        assert!(lfsr.next_bit());
+        assert!(!lfsr.next_bit());
+    }
+
+    // Test that an LFSR rejects the all-zero seed
+    #[test]
+    #[should_panic(expected = "seed must be non-zero")]
+    fn test_lfsr_zero_seed() {
+        use bits::lfsr::*;
+        Lfsr::new(LfsrMode::Galois, 4, 0b1100u8, 0);
+    }
+
+    // Test the integer n-th root
+    #[test]
+    fn test_ebm_iroot() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test perfect powers
+        assert_eq!(ebm_iroot(1000u32, 3), 10);
+        assert_eq!(ebm_iroot(1u64 << 60, 4), 1 << 15);
+        assert_eq!(ebm_iroot(3u128.pow(80), 80), 3);
+
+        // Test that non-perfect values round down
+        assert_eq!(ebm_iroot(999u32, 3), 9);
+        assert_eq!(ebm_iroot(1001u32, 3), 10);
+        assert_eq!(ebm_iroot(u64::MAX, 2), u32::MAX as u64);
+        assert_eq!(ebm_iroot(u64::MAX, 3), 2_642_245);
+
+        // Test the identity degree and the zero input
+        assert_eq!(ebm_iroot(12345u32, 1), 12345);
+        assert_eq!(ebm_iroot(0u32, 5), 0);
+        assert_eq!(ebm_iroot(1u32, 5), 1);
+
+        // Test degrees at or above the bit length
+        assert_eq!(ebm_iroot(255u8, 8), 1);
+        assert_eq!(ebm_iroot(255u8, 100), 1);
+
+        // Test every u16 for small degrees against the defining inequality
+        for n in 2u32..=5 {
+            for a in 0u16..=u16::MAX {
+                let root = ebm_iroot(a, n) as u64;
+                assert!(root.pow(n) <= a as u64 && (root + 1).pow(n) > a as u64);
+            }
+        }
+
+        // Test the full range of a signed type
+        for a in 0i8..=i8::MAX {
+            let root = ebm_iroot(a, 2) as i32;
+            assert!(root * root <= a as i32 && (root + 1) * (root + 1) > a as i32);
+        }
+    }
+
+    // Test the integer cube root
+    #[test]
+    fn test_ebm_icbrt() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test perfect cubes and their neighbors
+        assert_eq!(ebm_icbrt(27u32), 3);
+        assert_eq!(ebm_icbrt(26u32), 2);
+        assert_eq!(ebm_icbrt(28u32), 3);
+
+        // Test the zero case
+        assert_eq!(ebm_icbrt(0u32), 0);
+
+        // Test every u16 and every non-negative i8 against the defining inequality
+        for a in 0u16..=u16::MAX {
+            let root = ebm_icbrt(a) as u64;
+            assert!(root.pow(3) <= a as u64 && (root + 1).pow(3) > a as u64);
+        }
+        for a in 0i8..=i8::MAX {
+            let root = ebm_icbrt(a) as i32;
+            assert!(root.pow(3) <= a as i32 && (root + 1).pow(3) > a as i32);
+        }
+
+        // Test the extremes of wider types and agreement with the general root
+        assert_eq!(ebm_icbrt(u8::MAX), 6);
+        assert_eq!(ebm_icbrt(u32::MAX), 1625);
+        assert_eq!(ebm_icbrt(u64::MAX), 2_642_245);
+        assert_eq!(ebm_icbrt(u128::MAX), ebm_iroot(u128::MAX, 3));
+        assert_eq!(ebm_icbrt(i64::MAX), ebm_iroot(i64::MAX, 3));
+    }
+
+    // Test splitting values into digits
+    #[test]
+    fn test_ebm_to_digits() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_div::*;
+        use bits::format::*;
+
+        // Test the division helper
+        assert_eq!(ebm_divmod(17u8, 5u8), (3, 2));
+        assert_eq!(ebm_divmod(-7i32, 2i32), (-3, -1));
+
+        // Test common bases
+        assert_eq!(ebm_to_digits(255u32, 16), vec![15, 15]);
+        assert_eq!(ebm_to_digits(1234u32, 10), vec![4, 3, 2, 1]);
+        assert_eq!(ebm_to_digits(5u8, 2), vec![1, 0, 1]);
+        assert_eq!(ebm_to_digits(u64::MAX, 1 << 32), vec![u32::MAX, u32::MAX]);
+
+        // Test the zero value
+        assert_eq!(ebm_to_digits(0u32, 10), vec![0]);
+
+        // Test an unusual base and a signed type
+        assert_eq!(ebm_to_digits(100u16, 7), vec![2, 0, 2]);
+        assert_eq!(ebm_to_digits(127i8, 10), vec![7, 2, 1]);
+    }
+
+    // Test rebuilding values from digits
+    #[test]
+    fn test_ebm_from_digits() {
+        use bits::format::*;
+
+        // Test common bases
+        assert_eq!(ebm_from_digits(&[15, 15], 16u32), Some(255));
+        assert_eq!(ebm_from_digits(&[4, 3, 2, 1], 10u32), Some(1234));
+        assert_eq!(ebm_from_digits(&[], 10u32), Some(0));
+        assert_eq!(ebm_from_digits(&[7, 0, 0], 10u32), Some(7));
+
+        // Test the round-trip with ebm_to_digits
+        for a in [0u64, 1, 9, 10, 255, 65_535, 1 << 40, u64::MAX] {
+            for base in [2u64, 3, 10, 16, 36, 1000] {
+                assert_eq!(ebm_from_digits(&ebm_to_digits(a, base), base), Some(a));
+            }
+        }
+
+        // Test overflow of the target type
+        assert_eq!(ebm_from_digits(&[5, 5, 2], 10u8), Some(255));
+        assert_eq!(ebm_from_digits(&[6, 5, 2], 10u8), None);
+        assert_eq!(ebm_from_digits(&[8, 2, 1], 10i8), None);
+
+        // Test out-of-range digits
+        assert_eq!(ebm_from_digits(&[10], 10u8), None);
+        assert_eq!(ebm_from_digits(&[300], 10u8), None);
+        assert_eq!(ebm_from_digits(&[200], 10i8), None);
+    }
+
+    // Test the constant-time equality comparison
+    #[test]
+    fn test_ebm_ct_eq() {
+        use bits::constant_time::*;
+
+        // Test equal slices
+        assert!(ebm_ct_eq(b"tag-1234", b"tag-1234"));
+        assert!(ebm_ct_eq(&[], &[]));
+
+        // Test slices that differ in the first, a middle and the last byte
+        assert!(!ebm_ct_eq(b"tag-1234", b"Tag-1234"));
+        assert!(!ebm_ct_eq(b"tag-1234", b"tag_1234"));
+        assert!(!ebm_ct_eq(b"tag-1234", b"tag-1235"));
+
+        // Test a single differing bit
+        assert!(!ebm_ct_eq(&[0x00; 32], &[[0x00; 31].as_slice(), &[0x80]].concat()));
+
+        // Test slices of different lengths
+        assert!(!ebm_ct_eq(b"tag", b"tag-1234"));
+    }
+
+    // Test the constant-time conditional copy
+    #[test]
+    fn test_ebm_ct_copy() {
+        use bits::constant_time::*;
+
+        // Test the copy path
+        let mut dst = [0u8; 4];
+        ebm_ct_copy(&mut dst, &[1, 2, 3, 0xFF], true);
+        assert_eq!(dst, [1, 2, 3, 0xFF]);
+
+        // Test the no-copy path
+        ebm_ct_copy(&mut dst, &[9, 9, 9, 9], false);
+        assert_eq!(dst, [1, 2, 3, 0xFF]);
+
+        // Test a constant-time table select
+        let table = [[0x10u8; 4], [0x20; 4], [0x30; 4]];
+        let mut selected = [0u8; 4];
+        for (index, entry) in table.iter().enumerate() {
+            ebm_ct_copy(&mut selected, entry, index == 1);
+        }
+        assert_eq!(selected, [0x20; 4]);
+    }
+
+    // Test that the conditional copy rejects slices of different lengths
+    #[test]
+    #[should_panic]
+    fn test_ebm_ct_copy_length_mismatch() {
+        use bits::constant_time::*;
+        ebm_ct_copy(&mut [0u8; 2], &[1, 2, 3], true);
+    }
+
+    // Test iterating over k-subsets of n bits
+    #[test]
+    fn test_ebm_combinations() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+
+        // Computes the binomial coefficient C(n, k)
+        fn binomial(n: u64, k: u64) -> u64 {
+            (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+        }
+
+        // Test a small enumeration in full
+        let subsets: Vec<u64> = ebm_combinations(4, 2).collect();
+        assert_eq!(subsets, vec![0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]);
+
+        // Test that the counts match C(n, k) and the order is increasing
+        for n in 0..=12 {
+            for k in 0..=n {
+                let subsets: Vec<u64> = ebm_combinations(n, k).collect();
+                assert_eq!(subsets.len() as u64, binomial(n as u64, k as u64));
+                assert!(subsets.windows(2).all(|pair| pair[0] < pair[1]));
+                assert!(subsets.iter().all(|subset| subset.count_ones() == k && *subset >> n == 0));
+            }
+        }
+
+        // Test the first and last values at the full width
+        let mut subsets = ebm_combinations(64, 3);
+        assert_eq!(subsets.next(), Some(0b111));
+        assert_eq!(ebm_combinations(64, 3).last(), Some(0b111 << 61));
+        assert_eq!(ebm_combinations(64, 1).count(), 64);
+        assert_eq!(ebm_combinations(64, 64).collect::<Vec<_>>(), vec![u64::MAX]);
+
+        // Test the edge cases of empty subsets and impossible sizes
+        assert_eq!(ebm_combinations(5, 0).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(ebm_combinations(3, 4).count(), 0);
+    }
+
+    // Test the SWAR byte lane equality comparison
+    #[test]
+    fn test_ebm_cmp_bytes_eq() {
+        use bits::analysis::*;
+
+        // Test some equal and some differing lanes
+        assert_eq!(ebm_cmp_bytes_eq(0x1122_3344_5566_7788, 0x1122_0044_5566_7700), 0xFFFF_00FF_FFFF_FF00);
+
+        // Test all equal and all differing lanes
+        assert_eq!(ebm_cmp_bytes_eq(0, 0), u64::MAX);
+        assert_eq!(ebm_cmp_bytes_eq(u64::MAX, 0), 0);
+
+        // Test a 0x01 difference above an equal lane, which the borrow-based test would misflag
+        assert_eq!(ebm_cmp_bytes_eq(0x0000_0000_0000_0100, 0), 0xFFFF_FFFF_FFFF_00FF);
+
+        // Test lane by lane against a scalar reference
+        let a = u64::from_le_bytes([0x00, 0x80, 0x7F, 0xFF, 0x01, 0x10, 0x20, 0x30]);
+        let b = u64::from_le_bytes([0x00, 0x00, 0x7F, 0xFE, 0x01, 0x90, 0x20, 0x31]);
+        let result = ebm_cmp_bytes_eq(a, b).to_le_bytes();
+        for ((lane, left), right) in result.iter().zip(a.to_le_bytes()).zip(b.to_le_bytes()) {
+            assert_eq!(*lane, if left == right { 0xFF } else { 0x00 });
+        }
+    }
+
+    // Test the SWAR saturating byte-wise addition
+    #[test]
+    fn test_ebm_swar_add_saturating_u8x8() {
+        use bits::analysis::*;
+
+        // Computes the saturating lane sums one byte at a time
+        fn reference(a: u64, b: u64) -> u64 {
+            let sums: Vec<u8> = a.to_le_bytes().iter().zip(b.to_le_bytes()).map(|(&x, y)| x.saturating_add(y)).collect();
+            u64::from_le_bytes(sums.try_into().unwrap())
+        }
+
+        // Test lanes that saturate next to lanes that do not
+        assert_eq!(ebm_swar_add_saturating_u8x8(0x0102_03F0, 0x0101_0120), 0x0203_04FF);
+        assert_eq!(ebm_swar_add_saturating_u8x8(0x80FF_7F01, 0x8001_7F01), 0xFFFF_FE02);
+
+        // Test that a saturating lane does not carry into its neighbor
+        assert_eq!(ebm_swar_add_saturating_u8x8(0x00FF, 0x0001), 0x00FF);
+        assert_eq!(ebm_swar_add_saturating_u8x8(u64::MAX, 1), u64::MAX);
+
+        // Test pseudo-random words against the scalar reference
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        for _ in 0..1000 {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let a = state;
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let b = state;
+            assert_eq!(ebm_swar_add_saturating_u8x8(a, b), reference(a, b));
+        }
+    }
+
+    // Test splitting the even and odd bits
+    #[test]
+    fn test_ebm_even_odd_bits() {
+        use bits::morton::*;
+
+        // Test the basic split
+        assert_eq!(ebm_even_bits(0b1111u8), 0b11);
+        assert_eq!(ebm_odd_bits(0b1111u8), 0b11);
+        assert_eq!(ebm_even_bits(0b0101_0001u8), 0b1101);
+        assert_eq!(ebm_odd_bits(0b1010_0010u8), 0b1101);
+
+        // Test the full-width patterns
+        assert_eq!(ebm_even_bits(0x5555_5555u32), 0xFFFF);
+        assert_eq!(ebm_odd_bits(0x5555_5555u32), 0);
+        assert_eq!(ebm_odd_bits(u128::MAX), u64::MAX as u128);
+
+        // Test a signed value whose sign bit is an odd bit
+        assert_eq!(ebm_odd_bits(i16::MIN), 0x80);
+
+        // Test the agreement with the stride gather and the round-trip with spread
+        for a in [0u64, 1, 0xDEAD_BEEF_0123_4567, u64::MAX, 0x8000_0000_0000_0001] {
+            assert_eq!(ebm_even_bits(a), ebm_gather_bits(a, 2));
+            assert_eq!(ebm_odd_bits(a), ebm_gather_bits(a >> 1, 2));
+            assert_eq!(ebm_spread_bits(ebm_even_bits(a), 2) | (ebm_spread_bits(ebm_odd_bits(a), 2) << 1), a);
+        }
+    }
+
+    // Test the bit-reversal permutation table
+    #[test]
+    fn test_ebm_bit_reversal_table() {
+        use bits::bit_manipulation::*;
+
+        // Test the field reversal
+        assert_eq!(ebm_reverse_bits_n(0b001u8, 3), 0b100);
+        assert_eq!(ebm_reverse_bits_n(0xF1u8, 4), 0b1000);
+        assert_eq!(ebm_reverse_bits_n(-1i16, 5), 0b11111);
+        assert_eq!(ebm_reverse_bits_n(0x1234u16, 16), 0x1234u16.reverse_bits());
+        assert_eq!(ebm_reverse_bits_n(7u32, 0), 0);
+
+        // Test the three-bit table
+        assert_eq!(ebm_bit_reversal_table(3), vec![0, 4, 2, 6, 1, 5, 3, 7]);
+        assert_eq!(ebm_bit_reversal_table(0), vec![0]);
+
+        // Test that the table matches the reversed counter and is its own inverse
+        let table = ebm_bit_reversal_table(6);
+        let mut counter = 0u32;
+        for (index, &entry) in table.iter().enumerate() {
+            assert_eq!(entry, counter);
+            assert_eq!(table[entry as usize], index as u32);
+            counter = ebm_reverse_increment(counter, 6);
+        }
+    }
+
+    // Test checked negation
+    #[test]
+    fn test_ebm_checked_neg() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test the signed overflow case
+        assert_eq!(ebm_checked_neg(i8::MIN), None);
+        assert_eq!(ebm_checked_neg(i64::MIN), None);
+
+        // Test ordinary signed values
+        assert_eq!(ebm_checked_neg(5i8), Some(-5));
+        assert_eq!(ebm_checked_neg(i8::MAX), Some(-127));
+        assert_eq!(ebm_checked_neg(0i32), Some(0));
+
+        // Test unsigned values, where only zero can be negated
+        assert_eq!(ebm_checked_neg(0u16), Some(0));
+        assert_eq!(ebm_checked_neg(1u16), None);
+    }
+
+    // Test depositing bits into a mask with overflow detection
+    #[test]
+    fn test_ebm_try_pdep() {
+        use bits::bit_manipulation::*;
+
+        // Test the plain deposit
+        assert_eq!(ebm_pdep(0b101u8, 0b0111_0000), 0b0101_0000);
+        assert_eq!(ebm_pdep(0xFFu8, 0b1001_0010), 0b1001_0010);
+        assert_eq!(ebm_pdep(0x1234u16, 0xFFFF), 0x1234);
+        assert_eq!(ebm_pdep(0b1111u8, 0), 0);
+
+        // Test values that fit the mask
+        assert_eq!(ebm_try_pdep(0b11u8, 0b1010_0000), Some(0b1010_0000));
+        assert_eq!(ebm_try_pdep(0u32, 0), Some(0));
+        assert_eq!(ebm_try_pdep(-1i8, -1), Some(-1));
+
+        // Test values that overflow the mask's capacity
+        assert_eq!(ebm_try_pdep(0b111u8, 0b1010_0000), None);
+        assert_eq!(ebm_try_pdep(1u64, 0), None);
+        assert_eq!(ebm_try_pdep(-1i8, 0x7F), None);
+
+        // Test few set bits that sit above the mask's capacity are rejected, not dropped
+        assert_eq!(ebm_try_pdep(0b100u8, 0b1010_0000), None);
+        assert_eq!(ebm_try_pdep(0b1100u8, 0b0000_1011), None);
+    }
+
+    // Test rotating packed lanes independently
+    #[test]
+    fn test_ebm_rotate_lanes() {
+        use bits::bit_operations::bitwise_shifting::bitwise_shifting_advanced::bitwise_shifting_rotate::*;
+
+        // Test 8-bit lanes rotated by one, checking every lane on its own
+        let word = 0x8001_7FFE_C355_AA00u64;
+        let rotated = ebm_rotate_lanes(word, 8, 1);
+        for (lane, byte) in word.to_le_bytes().iter().enumerate() {
+            assert_eq!(rotated.to_le_bytes()[lane], byte.rotate_left(1));
+        }
+        assert_eq!(ebm_rotate_lanes(0x8001_0000_0000_0080, 8, 1), 0x0102_0000_0000_0001);
+
+        // Test the agreement with the subword rotate for other lane widths
+        for lane_bits in [1u32, 2, 4, 16, 32] {
+            for amount in 0..2 * lane_bits {
+                let rotated = ebm_rotate_lanes(word, lane_bits, amount);
+                for offset in (0..64).step_by(lane_bits as usize) {
+                    let lane = (word >> offset) & (u64::MAX >> (64 - lane_bits));
+                    let expected = ebm_rotate_subword(lane, lane_bits, amount);
+                    assert_eq!((rotated >> offset) & (u64::MAX >> (64 - lane_bits)), expected);
+                }
+            }
+        }
+
+        // Test the single full-width lane
+        assert_eq!(ebm_rotate_lanes(word, 64, 12), word.rotate_left(12));
+    }
+
+    // Test Hamming(7,4) encoding and single-error correction
+    #[test]
+    fn test_ebm_hamming74() {
+        use bits::ecc::*;
+
+        // Test the known codewords
+        assert_eq!(ebm_hamming74_encode(0b0000), 0b000_0000);
+        assert_eq!(ebm_hamming74_encode(0b1011), 0b101_0101);
+        assert_eq!(ebm_hamming74_encode(0b1111), 0b111_1111);
+
+        for nibble in 0..16u8 {
+            // Test the clean round-trip
+            let code = ebm_hamming74_encode(nibble);
+            assert!(code < 0x80);
+            assert_eq!(ebm_hamming74_decode(code), (nibble, false));
+
+            // Test that flipping any single bit is corrected
+            for bit in 0..7 {
+                assert_eq!(ebm_hamming74_decode(code ^ (1 << bit)), (nibble, true));
+            }
+        }
+
+        // Test that distinct codewords differ in at least three bits
+        for a in 0..16u8 {
+            for b in (a + 1)..16 {
+                assert!((ebm_hamming74_encode(a) ^ ebm_hamming74_encode(b)).count_ones() >= 3);
+            }
+        }
+    }
+
+    // Test the per-byte population count
+    #[test]
+    fn test_ebm_popcount_per_byte() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_population::*;
+
+        // Test the basic buffer
+        assert_eq!(ebm_popcount_per_byte(&[0x00, 0xFF, 0x0F]), vec![0, 8, 4]);
+
+        // Test the empty buffer and single bits
+        assert!(ebm_popcount_per_byte(&[]).is_empty());
+        assert_eq!(ebm_popcount_per_byte(&[0x80, 0x01, 0xAA, 0x7F]), vec![1, 1, 4, 7]);
+
+        // Test that the counts add up to the population count of the packed value
+        let word = 0xDEAD_BEEF_0123_4567u64;
+        let counts = ebm_popcount_per_byte(&word.to_le_bytes());
+        assert_eq!(counts.iter().map(|&count| count as u32).sum::<u32>(), word.count_ones());
+    }
+
+    // Test the masked population count
+    #[test]
+    fn test_ebm_popcount_masked() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_population::*;
+
+        // Test a mask selecting the high nibble
+        assert_eq!(ebm_popcount_masked(0xFFu8, 0xF0), 4);
+        assert_eq!(ebm_popcount_masked(0x1Fu8, 0xF0), 1);
+
+        // Test the empty and full masks
+        assert_eq!(ebm_popcount_masked(0xDEAD_BEEFu32, 0), 0);
+        assert_eq!(ebm_popcount_masked(0xDEAD_BEEFu32, u32::MAX), 0xDEAD_BEEFu32.count_ones());
+
+        // Test signed values
+        assert_eq!(ebm_popcount_masked(-1i64, i64::MIN), 1);
+    }
+
+    // Test reversing the order of bit groups
+    #[test]
+    fn test_ebm_reverse_groups() {
+        use bits::bit_manipulation::*;
+
+        // Test reversing nibbles
+        assert_eq!(ebm_reverse_groups(0x1234u16, 4), 0x4321);
+
+        // Test the special cases of bits, bytes and the whole value
+        assert_eq!(ebm_reverse_groups(0x1234_5678u32, 8), 0x1234_5678u32.swap_bytes());
+        assert_eq!(ebm_reverse_groups(0xDEAD_BEEFu32, 1), 0xDEAD_BEEFu32.reverse_bits());
+        assert_eq!(ebm_reverse_groups(0xDEAD_BEEFu32, 32), 0xDEAD_BEEF);
+
+        // Test other group sizes and signed values
+        assert_eq!(ebm_reverse_groups(0b11_10_01_00u8, 2), 0b00_01_10_11);
+        assert_eq!(ebm_reverse_groups(0x0123_4567_89AB_CDEFu64, 16), 0xCDEF_89AB_4567_0123);
+        assert_eq!(ebm_reverse_groups(-0x100i16, 8), 0x00FF);
+
+        // Test that reversing twice restores the value
+        assert_eq!(ebm_reverse_groups(ebm_reverse_groups(0xCAFEu16, 2), 2), 0xCAFE);
+    }
+
+    // Test locating the lowest differing bit
+    #[test]
+    fn test_ebm_first_diff_bit() {
+        use bits::analysis::*;
+
+        // Test equal inputs
+        assert_eq!(ebm_first_diff_bit(0u8, 0u8), None);
+        assert_eq!(ebm_first_diff_bit(0xDEAD_BEEFu32, 0xDEAD_BEEFu32), None);
+
+        // Test inputs differing only at bit 5
+        assert_eq!(ebm_first_diff_bit(0b0000_0000u8, 0b0010_0000u8), Some(5));
+        assert_eq!(ebm_first_diff_bit(0xFFFF_FFFF_FFFF_FFFFu64, 0xFFFF_FFFF_FFFF_FFDFu64), Some(5));
+
+        // Test that only the lowest difference counts, including the sign bit
+        assert_eq!(ebm_first_diff_bit(0b1001u8, 0b0011u8), Some(1));
+        assert_eq!(ebm_first_diff_bit(i16::MIN, 0i16), Some(15));
+    }
+
+    // Test the common prefix length of two values
+    #[test]
+    fn test_ebm_common_prefix_len() {
+        use bits::analysis::*;
+
+        // Test values differing only in the least significant bit
+        assert_eq!(ebm_common_prefix_len(0x1234u16, 0x1235u16), 15);
+        assert_eq!(ebm_common_prefix_len(0u64, 1u64), 63);
+
+        // Test equal values and values differing in the top bit
+        assert_eq!(ebm_common_prefix_len(0xABu8, 0xABu8), 8);
+        assert_eq!(ebm_common_prefix_len(0x80u8, 0x00u8), 0);
+        assert_eq!(ebm_common_prefix_len(-1i32, 1i32), 0);
+
+        // Test a prefix in the middle
+        assert_eq!(ebm_common_prefix_len(0b1010_0000u8, 0b1011_0000u8), 3);
+    }
+
+    // Test truncated subtraction
+    #[test]
+    fn test_ebm_monus() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_sub::*;
+
+        // Test the clamp at zero
+        assert_eq!(ebm_monus(3u8, 10u8), 0);
+        assert_eq!(ebm_monus(0u64, u64::MAX), 0);
+        assert_eq!(ebm_monus(5u32, 5u32), 0);
+
+        // Test ordinary differences
+        assert_eq!(ebm_monus(10u8, 3u8), 7);
+        assert_eq!(ebm_monus(u128::MAX, 1u128), u128::MAX - 1);
+
+        // Test signed values, which never go negative and saturate at the top
+        assert_eq!(ebm_monus(-5i8, 3i8), 0);
+        assert_eq!(ebm_monus(3i8, -5i8), 8);
+        assert_eq!(ebm_monus(i8::MAX, i8::MIN), i8::MAX);
+    }
+
+    // Test the intersection count of two bitmaps
+    #[test]
+    fn test_ebm_and_popcount() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+
+        // Test overlapping bitmaps
+        let a = [0xFF00_FF00_FF00_FF00u64, 0x0000_0000_FFFF_FFFF, u64::MAX];
+        let b = [0x0FF0_0FF0_0FF0_0FF0u64, 0xFFFF_FFFF_0000_0001, 0x8000_0000_0000_0001];
+        assert_eq!(ebm_and_popcount(&a, &b), 16 + 1 + 2);
+
+        // Test disjoint, identical and empty bitmaps
+        assert_eq!(ebm_and_popcount(&[0xF0], &[0x0F]), 0);
+        assert_eq!(ebm_and_popcount(&a, &a), a.iter().map(|word| word.count_ones() as u64).sum());
+        assert_eq!(ebm_and_popcount(&[], &[]), 0);
+    }
+
+    // Test that bitmaps of different lengths are rejected
+    #[test]
+    #[should_panic(expected = "bitmaps must have equal length")]
+    fn test_ebm_and_popcount_length_mismatch() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+        ebm_and_popcount(&[1, 2], &[1]);
+    }
+
+    // Test the union count of two bitmaps
+    #[test]
+    fn test_ebm_or_popcount() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+
+        // Test against a bit-by-bit reference on sample bitmaps
+        let a = [0xFF00_FF00_FF00_FF00u64, 0x0000_0000_FFFF_FFFF, 0];
+        let b = [0x0FF0_0FF0_0FF0_0FF0u64, 0xFFFF_FFFF_0000_0001, 0x8000_0000_0000_0001];
+        let mut reference = 0;
+        for (x, y) in a.iter().zip(&b) {
+            for bit in 0..64 {
+                if (x >> bit) & 1 == 1 || (y >> bit) & 1 == 1 {
+                    reference += 1;
+                }
+            }
+        }
+        assert_eq!(ebm_or_popcount(&a, &b), reference);
+
+        // Test the inclusion-exclusion identity with the intersection count
+        let total: u64 = a.iter().chain(&b).map(|word| word.count_ones() as u64).sum();
+        assert_eq!(ebm_or_popcount(&a, &b) + ebm_and_popcount(&a, &b), total);
+
+        // Test the empty bitmaps
+        assert_eq!(ebm_or_popcount(&[], &[]), 0);
+    }
+
+    // Test the Tanimoto similarity of two bitmaps
+    #[test]
+    fn test_ebm_tanimoto() {
+        use bits::analysis::*;
+
+        // Test partial overlap
+        assert!((ebm_tanimoto(&[0b1100], &[0b1010]) - 1.0 / 3.0).abs() < 1e-12);
+        assert!((ebm_tanimoto(&[u64::MAX, 0], &[u64::MAX, u64::MAX]) - 0.5).abs() < 1e-12);
+
+        // Test identical, disjoint and all-zero bitmaps
+        assert_eq!(ebm_tanimoto(&[0xDEAD, 0xBEEF], &[0xDEAD, 0xBEEF]), 1.0);
+        assert_eq!(ebm_tanimoto(&[0xF0], &[0x0F]), 0.0);
+        assert_eq!(ebm_tanimoto(&[0, 0], &[0, 0]), 1.0);
+        assert_eq!(ebm_tanimoto(&[], &[]), 1.0);
+    }
+
+    // Test that bitmaps of different lengths are rejected by the similarity
+    #[test]
+    #[should_panic(expected = "bitmaps must have equal length")]
+    fn test_ebm_tanimoto_length_mismatch() {
+        use bits::analysis::*;
+        ebm_tanimoto(&[1], &[1, 2]);
+    }
+
+    // Test building masks of low ones
+    #[test]
+    fn test_ebm_ones() {
+        use bits::bit_manipulation::*;
+
+        // Test the empty mask
+        assert_eq!(ebm_ones::<u32>(0), 0);
+
+        // Test the full-width mask and counts beyond the width
+        assert_eq!(ebm_ones::<u32>(32), u32::MAX);
+        assert_eq!(ebm_ones::<u8>(200), 0xFF);
+        assert_eq!(ebm_ones::<i16>(16), -1);
+
+        // Test a middle value and the agreement with the low mask
+        assert_eq!(ebm_ones::<u32>(12), 0xFFF);
+        assert_eq!(ebm_ones::<u128>(100), ebm_low_mask::<u128>(100));
+    }
+
+    // Test that subsets of more than 64 elements are rejected
+    #[test]
+    #[should_panic(expected = "at most 64 elements are supported")]
+    fn test_ebm_combinations_too_many_elements() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+        let _ = ebm_combinations(65, 1).count();
+    }
+
+    // Test reading a mixed-endian header with the byte cursor
+    #[test]
+    fn test_byte_cursor() {
+        use bits::stream::*;
+
+        // A header with a big-endian magic, little-endian fields and a signed big-endian offset
+        let header = [
+            0x89, 0x50, 0x4E, 0x47, // magic, big-endian u32
+            0x02, 0x00, // version, little-endian u16
+            0x78, 0x56, 0x34, 0x12, // length, little-endian u32
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, // count, big-endian u64
+            0xFF, 0xFE, // offset, big-endian i16
+            0x07, // flags
+        ];
+        let mut cursor = ByteCursor::new(&header);
+        assert_eq!(cursor.read_u32_be(), Some(0x8950_4E47));
+        assert_eq!(cursor.read_u16_le(), Some(2));
+        assert_eq!(cursor.read_u32_le(), Some(0x1234_5678));
+        assert_eq!(cursor.read_u64_be(), Some(256));
+        assert_eq!(cursor.read::<i16>(Endian::Big), Some(-2));
+        assert_eq!(cursor.position(), 20);
+        assert_eq!(cursor.remaining(), 1);
+
+        // Test that a short read fails without consuming anything
+        assert_eq!(cursor.read_u16_be(), None);
+        assert_eq!(cursor.position(), 20);
+        assert_eq!(cursor.read_u8(), Some(0x07));
+        assert_eq!(cursor.read_u8(), None);
+        assert_eq!(cursor.remaining(), 0);
+
+        // Test the remaining fixed-width readers against the std conversions
+        let bytes = 0x0123_4567_89AB_CDEFu64.to_le_bytes();
+        assert_eq!(ByteCursor::new(&bytes).read_u64_le(), Some(0x0123_4567_89AB_CDEF));
+        assert_eq!(ByteCursor::new(&bytes).read_u16_be(), Some(0xEFCD));
+        assert_eq!(ByteCursor::new(&bytes).read_u32_be(), Some(u32::from_be_bytes([0xEF, 0xCD, 0xAB, 0x89])));
+        assert_eq!(ByteCursor::new(&[]).read::<u128>(Endian::Little), None);
+    }
+
+    // Test the BMI1 lowest-set-bit family
+    #[test]
+    fn test_ebm_blsi_blsr_blsmsk() {
+        use bits::bit_manipulation::*;
+
+        // Test the mask up to and including the lowest set bit
+        assert_eq!(ebm_blsmsk(0b0101_0000u8), 0b0001_1111);
+        assert_eq!(ebm_blsmsk(1u32), 1);
+        assert_eq!(ebm_blsmsk(0u16), u16::MAX);
+        assert_eq!(ebm_blsmsk(i8::MIN), -1);
+
+        // Test isolating and resetting the lowest set bit
+        assert_eq!(ebm_blsi(0b0101_0000u8), 0b0001_0000);
+        assert_eq!(ebm_blsr(0b0101_0000u8), 0b0100_0000);
+        assert_eq!(ebm_blsi(0u64), 0);
+        assert_eq!(ebm_blsr(0u64), 0);
+        assert_eq!(ebm_blsr(i16::MIN), 0);
+
+        // Test the identities linking the three operations
+        for a in [1u32, 6, 0x8000_0000, 0xDEAD_BEEF, 0xFFFF_0000] {
+            assert_eq!(ebm_blsi(a) | ebm_blsr(a), a);
+            assert_eq!(ebm_blsmsk(a), ebm_below_lowest_set(a) | ebm_blsi(a));
+        }
+    }
+
+    // Test the bit-reversed counter spanning several limbs
+    #[test]
+    fn test_ebm_reverse_increment_wide() {
+        use bits::bit_manipulation::*;
+
+        // Test a 70-bit counter against bit-reversing each successive plain index
+        let mut counter = [0u64; 2];
+        for index in 0..1000u128 {
+            let reversed = index.reverse_bits() >> (128 - 70);
+            assert_eq!(counter, [reversed as u64, (reversed >> 64) as u64]);
+            ebm_reverse_increment_wide(&mut counter, 70);
+        }
+
+        // Test the wrap-around after all ones, leaving higher bits untouched
+        let mut full = [u64::MAX, 0b11_1111 | (1 << 40)];
+        ebm_reverse_increment_wide(&mut full, 70);
+        assert_eq!(full, [0, 1 << 40]);
+
+        // Test the agreement with the single-word counter
+        let mut wide = [0u64];
+        let mut narrow = 0u64;
+        for _ in 0..64 {
+            ebm_reverse_increment_wide(&mut wide, 6);
+            narrow = ebm_reverse_increment(narrow, 6);
+            assert_eq!(wide[0], narrow);
+        }
+    }
+
+    // Test the leading zero count across limbs
+    #[test]
+    fn test_ebm_leading_zeros_limbs() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_leading::*;
+
+        // Test leading all-zero limbs followed by a partially filled limb
+        assert_eq!(ebm_leading_zeros_limbs(&[0, 0, 0x0000_0000_0000_0100, u64::MAX]), 128 + 55);
+        assert_eq!(ebm_leading_zeros_limbs(&[0, 0x8000_0000_0000_0000]), 64);
+
+        // Test a partially filled top limb, where lower limbs do not matter
+        assert_eq!(ebm_leading_zeros_limbs(&[0x0000_FFFF_0000_0000, 0]), 16);
+        assert_eq!(ebm_leading_zeros_limbs(&[u64::MAX]), 0);
+
+        // Test all-zero and empty inputs
+        assert_eq!(ebm_leading_zeros_limbs(&[0, 0, 0]), 192);
+        assert_eq!(ebm_leading_zeros_limbs(&[]), 0);
+
+        // Test the agreement with u128 for two limbs
+        for value in [1u128, 0xFFFF_0000_0000_0000_0000, u128::MAX >> 3, 1 << 64] {
+            assert_eq!(ebm_leading_zeros_limbs(&[(value >> 64) as u64, value as u64]), value.leading_zeros() as u64);
+        }
+    }
+
+    // Test the bit length of a value
+    #[test]
+    fn test_ebm_bit_length() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+
+        // Test the requested u8 values
+        assert_eq!(ebm_bit_length(0u8), 0);
+        assert_eq!(ebm_bit_length(1u8), 1);
+        assert_eq!(ebm_bit_length(255u8), 8);
+
+        // Test the boundaries around powers of two
+        assert_eq!(ebm_bit_length(255u32), 8);
+        assert_eq!(ebm_bit_length(256u32), 9);
+        assert_eq!(ebm_bit_length(u128::MAX), 128);
+
+        // Test signed values
+        assert_eq!(ebm_bit_length(i16::MAX), 15);
+        assert_eq!(ebm_bit_length(-1i16), 16);
+    }
+
+    // Test truncating a value to its low bits
+    #[test]
+    fn test_ebm_truncate_to_bits() {
+        use bits::bit_manipulation::*;
+
+        // Test truncating to a nibble
+        assert_eq!(ebm_truncate_to_bits(0xFFu8, 4), 0x0F);
+
+        // Test the empty and full-width cuts
+        assert_eq!(ebm_truncate_to_bits(0xFFu8, 0), 0);
+        assert_eq!(ebm_truncate_to_bits(0xDEAD_BEEFu32, 32), 0xDEAD_BEEF);
+        assert_eq!(ebm_truncate_to_bits(0xDEAD_BEEFu32, 100), 0xDEAD_BEEF);
+
+        // Test signed values, which lose their sign-fill bits
+        assert_eq!(ebm_truncate_to_bits(-1i16, 12), 0x0FFF);
+        assert_eq!(ebm_truncate_to_bits(-1i16, 16), -1);
+    }
+
+    // Test ZigZag encoding and decoding of signed values
+    #[test]
+    fn test_ebm_zigzag() {
+        use bits::format::*;
+
+        // Test the small values
+        assert_eq!(ebm_zigzag_encode(0i32), 0u32);
+        assert_eq!(ebm_zigzag_encode(-1i32), 1u32);
+        assert_eq!(ebm_zigzag_encode(1i32), 2u32);
+        assert_eq!(ebm_zigzag_encode(-2i32), 3u32);
+        assert_eq!(ebm_zigzag_decode(1u32), -1i32);
+        assert_eq!(ebm_zigzag_decode(2u32), 1i32);
+
+        // Test the extremes
+        assert_eq!(ebm_zigzag_encode(i8::MAX), 254u8);
+        assert_eq!(ebm_zigzag_encode(i8::MIN), 255u8);
+        assert_eq!(ebm_zigzag_encode(i64::MIN), u64::MAX);
+
+        // Test round-tripping every i8 and a sample of wider values
+        for a in i8::MIN..=i8::MAX {
+            assert_eq!(ebm_zigzag_decode(ebm_zigzag_encode(a)), a);
+        }
+        for a in [i128::MIN, -123_456_789, 0, 987_654_321, i128::MAX] {
+            assert_eq!(ebm_zigzag_decode(ebm_zigzag_encode(a)), a);
+        }
+    }
+
+    // Test LEB128 varint encoding and decoding
+    #[test]
+    fn test_ebm_leb128() {
+        use bits::format::*;
+
+        // Test single-byte and multi-byte encodings
+        let mut out = Vec::new();
+        ebm_leb128_encode(0u32, &mut out);
+        ebm_leb128_encode(127u32, &mut out);
+        ebm_leb128_encode(128u32, &mut out);
+        ebm_leb128_encode(624_485u32, &mut out);
+        assert_eq!(out, vec![0x00, 0x7F, 0x80, 0x01, 0xE5, 0x8E, 0x26]);
+
+        // Test decoding a stream back to back using the consumed lengths
+        let mut offset = 0;
+        for expected in [0u32, 127, 128, 624_485] {
+            let (value, used) = ebm_leb128_decode::<u32>(&out[offset..]).unwrap();
+            assert_eq!(value, expected);
+            offset += used;
+        }
+        assert_eq!(offset, out.len());
+
+        // Test the continuation bit: only the first byte without it ends the value
+        assert_eq!(ebm_leb128_decode::<u32>(&[0x81, 0x01, 0x7F]), Some((129, 2)));
+        assert_eq!(ebm_leb128_decode::<u32>(&[0x80, 0x80, 0x00]), Some((0, 3)));
+
+        // Test truncated input and values too large for the type
+        assert_eq!(ebm_leb128_decode::<u32>(&[]), None);
+        assert_eq!(ebm_leb128_decode::<u32>(&[0xE5, 0x8E]), None);
+        assert_eq!(ebm_leb128_decode::<u8>(&[0x80, 0x02]), None);
+        assert_eq!(ebm_leb128_decode::<u8>(&[0xFF, 0x01]), Some((255, 2)));
+
+        // Test round-tripping the extremes of several types
+        for value in [0u64, 1, 0x3FFF, 0x4000, u64::MAX] {
+            let mut buffer = Vec::new();
+            ebm_leb128_encode(value, &mut buffer);
+            assert_eq!(ebm_leb128_decode::<u64>(&buffer), Some((value, buffer.len())));
+        }
+        let mut buffer = Vec::new();
+        ebm_leb128_encode(u64::MAX, &mut buffer);
+        assert_eq!(buffer.len(), 10);
+        let mut buffer = Vec::new();
+        ebm_leb128_encode(-1i8, &mut buffer);
+        assert_eq!(buffer, vec![0xFF, 0x01]);
+        assert_eq!(ebm_leb128_decode::<i8>(&buffer), Some((-1, 2)));
+        let mut buffer = Vec::new();
+        ebm_leb128_encode(ebm_zigzag_encode(-3i64), &mut buffer);
+        assert_eq!(buffer, vec![5]);
+    }
+
+    // Test the avalanche score separates strong mixers from bit-preserving functions
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_ebm_avalanche_score() {
+        use bits::testing::*;
+        use bits::bit_manipulation::ebm_reverse_bits;
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::ebm_mix;
+
+        // Test the identity and a bit permutation flip exactly one output bit
+        assert!((ebm_avalanche_score(|x: u64| x, 64) - 1.0 / 64.0).abs() < 1e-12);
+        assert!(ebm_avalanche_score(|x: u32| ebm_reverse_bits(x), 64) < 0.05);
+
+        // Test reversal composed with two mixing rounds flips about half of the output bits
+        let score = ebm_avalanche_score(|x: u64| ebm_mix(ebm_mix(ebm_reverse_bits(x), 1), 2), 256);
+        assert!((0.48..=0.52).contains(&score));
+        let score = ebm_avalanche_score(|x: u32| ebm_mix(ebm_mix(ebm_reverse_bits(x), 1), 2), 256);
+        assert!((0.48..=0.52).contains(&score));
+
+        // Test zero samples score zero
+        assert_eq!(ebm_avalanche_score(|x: u8| x, 0), 0.0);
+    }
+
+    // Test SWAR lane-wise minimum and maximum of packed bytes
+    #[test]
+    fn test_ebm_swar_min_max_u8x8() {
+        use bits::analysis::*;
+
+        // Test the documented examples
+        assert_eq!(ebm_swar_min_u8x8(0x10FF_8001, 0x2000_7F02), 0x1000_7F01);
+        assert_eq!(ebm_swar_max_u8x8(0x10FF_8001, 0x2000_7F02), 0x20FF_8002);
+
+        // Test every lane against a scalar reference, including top-bit boundaries
+        let lanes = [0x00u8, 0x01, 0x7E, 0x7F, 0x80, 0x81, 0xFE, 0xFF];
+        for &x in &lanes {
+            for &y in &lanes {
+                let a = u64::from_le_bytes([x, y, x, y, 0x7F, 0x80, x, y]);
+                let b = u64::from_le_bytes([y, x, x, 0x80, y, x, 0x00, 0xFF]);
+                let min = ebm_swar_min_u8x8(a, b).to_le_bytes();
+                let max = ebm_swar_max_u8x8(a, b).to_le_bytes();
+                for lane in 0..8 {
+                    let (p, q) = (a.to_le_bytes()[lane], b.to_le_bytes()[lane]);
+                    assert_eq!(min[lane], p.min(q));
+                    assert_eq!(max[lane], p.max(q));
+                }
+            }
+        }
+    }
+
+    // Test counting matching bytes a word at a time
+    #[test]
+    fn test_ebm_count_bytes_eq() {
+        use bits::analysis::*;
+
+        // Test the documented examples
+        assert_eq!(ebm_count_bytes_eq(b"a,b,,c,d,e", b','), 5);
+        assert_eq!(ebm_count_bytes_eq(b"", b','), 0);
+
+        // Test adjacent matches and a needle of 0x01 next to zero bytes are not overcounted
+        assert_eq!(ebm_count_bytes_eq(&[0x01, 0x00, 0x01, 0x01, 0x00, 0x00, 0x01, 0x01], 0x00), 3);
+        assert_eq!(ebm_count_bytes_eq(&[0x01, 0x00, 0x01, 0x01, 0x00, 0x00, 0x01, 0x01], 0x01), 5);
+
+        // Test against a naive count at several positions and in the unaligned tail
+        let mut data = [0x5Au8; 45];
+        for position in [0, 7, 8, 15, 16, 17, 40, 43, 44] {
+            data[position] = b'\n';
+        }
+        for length in 0..=data.len() {
+            for needle in [b'\n', 0x5A, 0x00] {
+                let naive = data[..length].iter().filter(|&&byte| byte == needle).count();
+                assert_eq!(ebm_count_bytes_eq(&data[..length], needle), naive);
+            }
+        }
+    }
+
+    // Test addition with an incoming and outgoing carry
+    #[test]
+    fn test_ebm_carrying_add() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_add::*;
+
+        // Test every carry-in combined with overflowing and non-overflowing sums against a wide reference
+        for a in [0u8, 1, 127, 128, 254, 255] {
+            for b in [0u8, 1, 127, 128, 254, 255] {
+                for carry in [false, true] {
+                    let exact = u16::from(a) + u16::from(b) + u16::from(carry);
+                    assert_eq!(ebm_carrying_add(a, b, carry), (exact as u8, exact > 255));
+                }
+            }
+        }
+
+        // Test a carry-in that turns a non-overflowing sum into an overflow
+        assert_eq!(ebm_carrying_add(u64::MAX, 0, true), (0, true));
+        assert_eq!(ebm_carrying_add(u64::MAX, u64::MAX, true), (u64::MAX, true));
+
+        // Test signed overflow of the whole sum, including cancelling overflows
+        for a in [i8::MIN, -1, 0, 1, i8::MAX] {
+            for b in [i8::MIN, -1, 0, 1, i8::MAX] {
+                for carry in [false, true] {
+                    let exact = i16::from(a) + i16::from(b) + i16::from(carry);
+                    let fits = (i16::from(i8::MIN)..=i16::from(i8::MAX)).contains(&exact);
+                    assert_eq!(ebm_carrying_add(a, b, carry), (exact as i8, !fits));
+                }
+            }
+        }
+    }
+
+    // Test subtraction with an incoming and outgoing borrow
+    #[test]
+    fn test_ebm_borrowing_sub() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_sub::*;
+
+        // Test 0 - 0 - borrow, which borrows only when the borrow is set
+        assert_eq!(ebm_borrowing_sub(0u8, 0u8, false), (0, false));
+        assert_eq!(ebm_borrowing_sub(0u8, 0u8, true), (255, true));
+        assert_eq!(ebm_borrowing_sub(0u64, 0u64, true), (u64::MAX, true));
+
+        // Test every borrow-in against a wide reference
+        for a in [0u8, 1, 127, 128, 254, 255] {
+            for b in [0u8, 1, 127, 128, 254, 255] {
+                for borrow in [false, true] {
+                    let exact = i16::from(a) - i16::from(b) - i16::from(borrow);
+                    assert_eq!(ebm_borrowing_sub(a, b, borrow), (exact as u8, exact < 0));
+                }
+            }
+        }
+
+        // Test a borrow propagating through a two-limb subtraction: 2^64 - 1
+        let (low, borrow) = ebm_borrowing_sub(0u64, 1u64, false);
+        let (high, borrow) = ebm_borrowing_sub(1u64, 0u64, borrow);
+        assert_eq!((low, high, borrow), (u64::MAX, 0, false));
+
+        // Test signed overflow of the whole difference, including cancelling overflows
+        for a in [i8::MIN, -1, 0, 1, i8::MAX] {
+            for b in [i8::MIN, -1, 0, 1, i8::MAX] {
+                for borrow in [false, true] {
+                    let exact = i16::from(a) - i16::from(b) - i16::from(borrow);
+                    let fits = (i16::from(i8::MIN)..=i16::from(i8::MAX)).contains(&exact);
+                    assert_eq!(ebm_borrowing_sub(a, b, borrow), (exact as i8, !fits));
+                }
+            }
+        }
+    }
+
+    // Test the fused multiply-accumulate step of limb multiplication
+    #[test]
+    fn test_ebm_mul_add_carry() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test the maximum inputs fill the full high limb without overflowing
+        assert_eq!(ebm_mul_add_carry(u64::MAX, u64::MAX, u64::MAX, u64::MAX), (u64::MAX, u64::MAX));
+        assert_eq!(ebm_mul_add_carry(u64::MAX, u64::MAX, 0, 0), (1, u64::MAX - 1));
+
+        // Test small values stay in the low limb
+        assert_eq!(ebm_mul_add_carry(3, 4, 5, 6), (23, 0));
+
+        // Test pseudo-random inputs against u128 arithmetic
+        let mut state = 0x0123_4567_89AB_CDEFu64;
+        for _ in 0..1000 {
+            let mut next = || {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state
+            };
+            let (a, b, c, carry) = (next(), next(), next(), next());
+            let wide = u128::from(a) * u128::from(b) + u128::from(c) + u128::from(carry);
+            assert_eq!(ebm_mul_add_carry(a, b, c, carry), (wide as u64, (wide >> 64) as u64));
+        }
+    }
+
+    // Test schoolbook multiplication of limb slices
+    #[test]
+    fn test_ebm_mul_limbs() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test the documented examples
+        let mut out = [0u64; 3];
+        ebm_mul_limbs(&[u64::MAX, 1], &[2], &mut out);
+        assert_eq!(out, [u64::MAX - 1, 3, 0]);
+
+        // Test 2-limb products against u128 arithmetic, including the high limbs
+        let mut state = 0xDEAD_BEEF_CAFE_F00Du64;
+        for _ in 0..500 {
+            let mut next = || {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state
+            };
+            let (a0, a1, b0) = (next(), next() >> 32, next());
+            let a = (u128::from(a1) << 64) | u128::from(a0);
+            let product = a.wrapping_mul(u128::from(b0));
+            let mut out = [u64::MAX; 3];
+            ebm_mul_limbs(&[a0, a1], &[b0], &mut out);
+            assert_eq!(out[0], product as u64);
+            assert_eq!(out[1], (product >> 64) as u64);
+
+            // Test the top limb against the carry a u128 cannot hold
+            let top = (u128::from(a1) * u128::from(b0) + ((u128::from(a0) * u128::from(b0)) >> 64)) >> 64;
+            assert_eq!(out[2], top as u64);
+        }
+
+        // Test the largest 2x2 product writes every limb, including the high limb
+        let mut out = [0u64; 4];
+        ebm_mul_limbs(&[u64::MAX, u64::MAX], &[u64::MAX, u64::MAX], &mut out);
+        assert_eq!(out, [1, 0, u64::MAX - 1, u64::MAX]);
+
+        // Test stale contents of the output are overwritten
+        let mut out = [7u64; 4];
+        ebm_mul_limbs(&[3, 0], &[5, 0], &mut out);
+        assert_eq!(out, [15, 0, 0, 0]);
+    }
+
+    // Test a mismatched output length is rejected
+    #[test]
+    #[should_panic(expected = "product needs a.len() + b.len() limbs")]
+    fn test_ebm_mul_limbs_length_mismatch() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        let mut out = [0u64; 3];
+        ebm_mul_limbs(&[1, 2], &[3, 4], &mut out);
+    }
+
+    // Test counting the positions where two values agree
+    #[test]
+    fn test_ebm_agreement() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+
+        // Test identical inputs agree everywhere
+        assert_eq!(ebm_agreement(0x5Au8, 0x5Au8), 8);
+        assert_eq!(ebm_agreement(0u64, 0u64), 64);
+        assert_eq!(ebm_agreement(-1i128, -1i128), 128);
+
+        // Test complementary inputs agree nowhere
+        assert_eq!(ebm_agreement(0x5Au8, 0xA5u8), 0);
+        assert_eq!(ebm_agreement(0x1234_5678u32, !0x1234_5678u32), 0);
+
+        // Test shared zeros count as matches
+        assert_eq!(ebm_agreement(0b1100u8, 0b1010u8), 6);
+        assert_eq!(ebm_agreement(0u16, 1u16), 15);
+    }
+
+    // Test framing bytes with a parity bit and validating it
+    #[test]
+    fn test_ebm_parity_frame() {
+        use bits::ecc::*;
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::ebm_parity;
+
+        // Test the parity bit for even and odd parity
+        assert_eq!(ebm_append_parity(0b0000_0011, true), 0b0_0000_0011);
+        assert_eq!(ebm_append_parity(0b0000_0111, true), 0b1_0000_0111);
+        assert_eq!(ebm_append_parity(0b0000_0011, false), 0b1_0000_0011);
+        assert_eq!(ebm_append_parity(0, false), 0b1_0000_0000);
+
+        // Test every byte round-trips with either parity
+        for byte in 0..=255u8 {
+            for even in [true, false] {
+                let frame = ebm_append_parity(byte, even);
+                assert_eq!(!ebm_parity(frame), even);
+                assert_eq!(ebm_check_parity(frame, even), Some(byte));
+
+                // Test every single-bit corruption of the frame is detected
+                for bit in 0..9 {
+                    assert_eq!(ebm_check_parity(frame ^ (1 << bit), even), None);
+                }
+            }
+        }
+
+        // Test frames with bits above the parity bit are rejected
+        assert_eq!(ebm_check_parity(0b11_0000_0000, true), None);
+        assert_eq!(ebm_check_parity(0x8000 | ebm_append_parity(0x42, false), false), None);
+    }
+
+    // Test interleaving two values into one of twice the width
+    #[test]
+    fn test_ebm_interleave() {
+        use bits::morton::*;
+
+        // Test the requested example and its mirror
+        assert_eq!(ebm_interleave(0b11u8, 0b00u8), 0b0101u16);
+        assert_eq!(ebm_interleave(0b00u8, 0b11u8), 0b1010u16);
+
+        // Test the top bits of both inputs land in the top of the wide type
+        assert_eq!(ebm_interleave(0x80u8, 0x80u8), 0xC000u16);
+        assert_eq!(ebm_interleave(u32::MAX, 0u32), 0x5555_5555_5555_5555u64);
+        assert_eq!(ebm_interleave(u64::MAX, u64::MAX), u128::MAX);
+
+        // Test against a bit-by-bit reference
+        let (a, b) = (0xB4u8, 0x6Du8);
+        let mut expected = 0u16;
+        for k in 0..8 {
+            expected |= u16::from((a >> k) & 1) << (2 * k);
+            expected |= u16::from((b >> k) & 1) << (2 * k + 1);
+        }
+        assert_eq!(ebm_interleave(a, b), expected);
+    }
+
+    // Test the double-width type pairing
+    #[test]
+    fn test_ebm_widen_narrow() {
+        use bits::ebm_int::*;
+
+        // Test widening zero-extends and narrowing keeps the low half
+        assert_eq!(0xFFu8.widen(), 0x00FFu16);
+        assert_eq!(u64::MAX.widen(), u128::from(u64::MAX));
+        assert_eq!(0x1234u16.narrow(), 0x34u8);
+        assert_eq!(0x1_0000_0002u64.narrow(), 2u32);
+    }
+
+    // Test splitting a value into its even and odd bits
+    #[test]
+    fn test_ebm_deinterleave() {
+        use bits::morton::*;
+
+        // Test the half-width results of a u16
+        let (even, odd): (u8, u8) = ebm_deinterleave(0b0101u16);
+        assert_eq!((even, odd), (0b11, 0b00));
+        assert_eq!(ebm_deinterleave(0xC000u16), (0x80u8, 0x80u8));
+        assert_eq!(ebm_deinterleave(0x5555_5555_5555_5555u64), (u32::MAX, 0u32));
+        assert_eq!(ebm_deinterleave(u128::MAX), (u64::MAX, u64::MAX));
+
+        // Test the round trip with interleave for several inputs
+        for (a, b) in [(0u8, 0u8), (0xFF, 0x00), (0xB4, 0x6D), (0x01, 0x80), (0xFF, 0xFF)] {
+            assert_eq!(ebm_deinterleave(ebm_interleave(a, b)), (a, b));
+        }
+        for (a, b) in [(0x1234_5678u32, 0x9ABC_DEF0u32), (u32::MAX, 1)] {
+            assert_eq!(ebm_deinterleave(ebm_interleave(a, b)), (a, b));
+        }
+        for code in [0u16, 1, 0x8000, 0xA5C3, u16::MAX] {
+            let (even, odd) = ebm_deinterleave(code);
+            assert_eq!(ebm_interleave(even, odd), code);
+        }
+    }
+
+    // Test power-of-two bucket indices
+    #[test]
+    fn test_ebm_log2_bucket() {
+        use bits::analysis::*;
+
+        // Test zero and one share bucket 0
+        assert_eq!(ebm_log2_bucket(0u32), 0);
+        assert_eq!(ebm_log2_bucket(1u32), 0);
+
+        // Test the boundaries around every power of two
+        for k in 1..64u32 {
+            let power = 1u64 << k;
+            assert_eq!(ebm_log2_bucket(power - 1), k - 1);
+            assert_eq!(ebm_log2_bucket(power), k);
+            assert_eq!(ebm_log2_bucket(power + 1), k);
+        }
+
+        // Test the top of the range
+        assert_eq!(ebm_log2_bucket(u8::MAX), 7);
+        assert_eq!(ebm_log2_bucket(u128::MAX), 127);
+        assert_eq!(ebm_log2_bucket(-1i16), 15);
+    }
+
+    // Test scaling by a ratio without intermediate overflow
+    #[test]
+    fn test_ebm_mul_div() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        // Test a product that overflows u32 while the result fits
+        assert!(3_000_000_000u32.checked_mul(3).is_none());
+        assert_eq!(ebm_mul_div(3_000_000_000u32, 3, 4), 2_250_000_000);
+        assert_eq!(ebm_mul_div(u64::MAX, u64::MAX, u64::MAX), u64::MAX);
+        assert_eq!(ebm_mul_div(u32::MAX, 1_000_000, 1_000_001), 4_294_963_000);
+
+        // Test truncation toward zero
+        assert_eq!(ebm_mul_div(7u64, 1, 2), 3);
+        assert_eq!(ebm_mul_div(0u16, 500, 3), 0);
+
+        // Test quotients that do not fit saturate
+        assert_eq!(ebm_mul_div(200u8, 200, 100), u8::MAX);
+        assert_eq!(ebm_mul_div(u32::MAX, 2, 1), u32::MAX);
+    }
+
+    // Test a zero denominator is rejected
+    #[test]
+    #[should_panic(expected = "denominator must be non-zero")]
+    fn test_ebm_mul_div_zero_denominator() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::other_related::*;
+
+        ebm_mul_div(1u32, 1, 0);
+    }
+
+    // Test rounding and ceiling division
+    #[test]
+    fn test_ebm_div_round_ceil() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_div::*;
+
+        // Test the requested values
+        assert_eq!(ebm_div_round(7u32, 2u32), 4);
+        assert_eq!(ebm_div_ceil(7u32, 2u32), 4);
+        assert_eq!(ebm_div_ceil(8u32, 2u32), 4);
+
+        // Test rounding around the half-way point
+        assert_eq!(ebm_div_round(9u32, 4u32), 2);
+        assert_eq!(ebm_div_round(10u32, 4u32), 3);
+        assert_eq!(ebm_div_round(11u32, 4u32), 3);
+        assert_eq!(ebm_div_round(4u8, 3u8), 1);
+        assert_eq!(ebm_div_round(5u8, 3u8), 2);
+
+        // Test against the standard ceiling division and the textbook rounding formula
+        for a in 0..=200u16 {
+            for b in 1..=50u16 {
+                assert_eq!(ebm_div_ceil(a, b), a.div_ceil(b));
+                assert_eq!(ebm_div_round(a, b), (a + b / 2) / b);
+            }
+        }
+
+        // Test dividends near the maximum do not overflow
+        assert_eq!(ebm_div_ceil(u64::MAX, 2), 1u64 << 63);
+        assert_eq!(ebm_div_round(u64::MAX, 2), 1u64 << 63);
+        assert_eq!(ebm_div_round(u8::MAX, 1), u8::MAX);
+        assert_eq!(ebm_div_ceil(i32::MAX, 1), i32::MAX);
+    }
+
+    // Test a zero divisor is rejected
+    #[test]
+    #[should_panic(expected = "divisor must be non-zero")]
+    fn test_ebm_div_ceil_zero_divisor() {
+        use bits::bit_operations::bitwise_arithmetic::bitwise_arithmetic_advanced::bitwise_arithmetic_div::*;
+
+        ebm_div_ceil(1u32, 0);
+    }
+
+    // Test the number of bits needed to index n values
+    #[test]
+    fn test_ebm_bits_for_values() {
+        use bits::analysis::*;
+
+        // Test the requested boundary
+        assert_eq!(ebm_bits_for_values(256), 8);
+        assert_eq!(ebm_bits_for_values(257), 9);
+
+        // Test zero and one value need no bits
+        assert_eq!(ebm_bits_for_values(0), 0);
+        assert_eq!(ebm_bits_for_values(1), 0);
+        assert_eq!(ebm_bits_for_values(2), 1);
+        assert_eq!(ebm_bits_for_values(3), 2);
+
+        // Test every power of two and the values just above it
+        for k in 1..64u32 {
+            assert_eq!(ebm_bits_for_values(1u64 << k), k);
+            assert_eq!(ebm_bits_for_values((1u64 << k) + 1), k + 1);
+        }
+        assert_eq!(ebm_bits_for_values(u64::MAX), 64);
+    }
+
+    // Test Kernighan's population count against the hardware count
+    #[test]
+    fn test_ebm_popcount_sparse() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::bitwise_counting_population::*;
+
+        // Test sparse, dense and signed values
+        assert_eq!(ebm_popcount_sparse(0u64), 0);
+        assert_eq!(ebm_popcount_sparse(0x8000_0000_0000_0001u64), 2);
+        assert_eq!(ebm_popcount_sparse(u128::MAX), 128);
+        assert_eq!(ebm_popcount_sparse(-1i8), 8);
+        assert_eq!(ebm_popcount_sparse(i64::MIN), 1);
+
+        // Test pseudo-random inputs match count_ones, including sparse ones
+        let mut state = 0x2545_F491_4F6C_DD1Du64;
+        for _ in 0..1000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            assert_eq!(ebm_popcount_sparse(state), state.count_ones());
+            assert_eq!(ebm_popcount_sparse(state & (state >> 3) & (state >> 7)), (state & (state >> 3) & (state >> 7)).count_ones());
+            assert_eq!(ebm_popcount_sparse(state as i32), (state as i32).count_ones());
+        }
+    }
+
+    // Test validating contiguous field masks
+    #[test]
+    fn test_ebm_is_contiguous_mask() {
+        use bits::analysis::*;
+
+        // Test the requested values
+        assert!(ebm_is_contiguous_mask(0b0011_1100u8));
+        assert!(!ebm_is_contiguous_mask(0b0011_0100u8));
+        assert!(ebm_is_contiguous_mask(0u8));
+
+        // Test runs touching either end of the type
+        assert!(ebm_is_contiguous_mask(u32::MAX));
+        assert!(ebm_is_contiguous_mask(0xFFFF_0000u32));
+        assert!(ebm_is_contiguous_mask(0x0000_FFFFu32));
+        assert!(ebm_is_contiguous_mask(i8::MIN));
+        assert!(ebm_is_contiguous_mask(-4i16));
+        assert!(!ebm_is_contiguous_mask(0x8000_0001u32));
+
+        // Test against a reference over every u8 mask
+        for mask in 0..=255u8 {
+            let expected = mask == 0 || (mask >> mask.trailing_zeros()).count_ones() == 8 - mask.leading_zeros() - mask.trailing_zeros();
+            assert_eq!(ebm_is_contiguous_mask(mask), expected);
+        }
+    }
+
+    // Test the parity of the set bits
+    #[test]
+    fn test_ebm_parity() {
+        use bits::bit_operations::bitwise_counting::bitwise_counting_advanced::other_related::*;
+
+        // Test odd and even counts
+        assert!(ebm_parity(0b0111u8));
+        assert!(!ebm_parity(0b0011u8));
+        assert!(!ebm_parity(0u64));
+        assert!(!ebm_parity(-1i32));
+        assert!(ebm_parity(i128::MIN));
+
+        // Test against the XOR of all bits
+        for value in 0..=u16::MAX {
+            let xor = (0..16).fold(0, |acc, bit| acc ^ ((value >> bit) & 1));
+            assert_eq!(ebm_parity(value), xor == 1);
+        }
+    }
+
+    // Test a permutation source outside the type is rejected
+    #[test]
+    #[should_panic(expected = "permutation source bit out of range")]
+    fn test_ebm_permute_bits_source_out_of_range() {
+        use bits::bit_manipulation::*;
+
+        ebm_permute_bits(0xFFu8, &[0, 8]);
+    }
 }